@@ -18,6 +18,10 @@ import {
     buildAdminSettlePollIx,
     buildClaimRewardIx,
     getPollPDA,
+    getVotePDA,
+    parsePollAccount,
+    parseVoteAccount,
+    quoteCastVote,
     connection,
 } from "@/lib/program";
 import { isSupabaseConfigured } from "@/lib/supabase";
@@ -763,13 +767,7 @@ export function usePollOperations({
                     return false;
                 }
 
-                const cost = numCoins * poll.unitPriceLamports;
                 const currentUser = usersRef.current.find(u => u.wallet === walletAddress);
-                if (currentUser && cost > currentUser.balance) {
-                    toast.error("Insufficient SOL balance");
-                    return false;
-                }
-
                 const prevPolls = currentPolls;
                 const prevVotes = currentVotes;
 
@@ -778,18 +776,30 @@ export function usePollOperations({
                     const pollCreator = new PublicKey(poll.creator);
                     toast.loading("Casting vote...", { id: "cast-vote" });
 
-                    // ── Pre-flight checks + instruction build in parallel ──
+                    // ── Pre-flight reads in parallel ──
                     const [pollPDA] = getPollPDA(pollCreator, poll.pollId);
-                    const [pollInfo, existingUser, voteIx] = await Promise.all([
+                    const [votePDA] = getVotePDA(pollPDA, pubkey);
+                    const [pollInfo, voteInfo, existingUser] = await Promise.all([
                         connection.getAccountInfo(pollPDA),
+                        connection.getAccountInfo(votePDA),
                         fetchUserAccount(pubkey),
-                        buildCastVoteIx(pubkey, pollCreator, poll.pollId, optionIndex, numCoins),
                     ]);
                     if (!pollInfo) {
                         toast.error("This poll does not exist on-chain. It may have been created before on-chain mode was enabled.", { id: "cast-vote" });
                         return false;
                     }
 
+                    // ── Quote against live on-chain state (bonding curves, own holdings) ──
+                    const held = voteInfo
+                        ? parseVoteAccount(votePDA, voteInfo.data as Buffer).votesPerOption[optionIndex] ?? 0
+                        : 0;
+                    const cost = Number(quoteCastVote(parsePollAccount(pollPDA, pollInfo.data as Buffer), optionIndex, numCoins, held));
+                    if (currentUser && cost > currentUser.balance) {
+                        toast.error("Insufficient SOL balance", { id: "cast-vote" });
+                        return false;
+                    }
+                    const voteIx = await buildCastVoteIx(pubkey, pollCreator, poll.pollId, optionIndex, numCoins, cost);
+
                     const instructions = [];
                     if (!existingUser) {
                         instructions.push(await buildInitializeUserIx(pubkey));
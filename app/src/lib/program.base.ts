@@ -58,6 +58,14 @@ export function getTreasuryPDA(pollAccount: PublicKey): [PublicKey, number] {
     );
 }
 
+/** seeds = ["order_book", poll_account_pubkey] */
+export function getOrderBookPDA(pollAccount: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("order_book"), pollAccount.toBuffer()],
+        PROGRAM_ID
+    );
+}
+
 /** seeds = ["vote", poll_account_pubkey, voter] */
 export function getVotePDA(pollAccount: PublicKey, voter: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
@@ -18,6 +18,7 @@ import {
     getUserPDA,
     getPollPDA,
     getTreasuryPDA,
+    getOrderBookPDA,
     getVotePDA,
     ixDiscriminator,
     accountDiscriminator,
@@ -209,6 +210,15 @@ export type OnChainPoll = {
     bump: number;
     totalVoters: number;
     createdAt: number;
+    setCollateral: number;
+    priceCurves: OnChainPriceCurve[];
+};
+
+/** Per-option bonding curve — mirrors `PriceCurve` in state.rs */
+export type OnChainPriceCurve = {
+    /** 0 = flat, 1 = linear, 2 = exponential, 3 = quadratic */
+    kind: number;
+    param: bigint;
 };
 
 export type OnChainVote = {
@@ -264,9 +274,20 @@ export function parsePollAccount(address: PublicKey, data: Buffer): OnChainPoll
         bump: reader.readU8(),
         totalVoters: reader.readU32(),
         createdAt: reader.readI64AsNumber(),
+        setCollateral: reader.readU64AsNumber(),
+        priceCurves: readVec(reader, () => ({ kind: reader.readU8(), param: reader.readU64() })),
     };
 }
 
+function readVec<T>(reader: BorshReader, readItem: () => T): T[] {
+    const count = reader.readU32();
+    const result: T[] = [];
+    for (let i = 0; i < count; i++) {
+        result.push(readItem());
+    }
+    return result;
+}
+
 export function parseVoteAccount(address: PublicKey, data: Buffer): OnChainVote {
     const reader = new BorshReader(data);
     reader.skip(8); // Skip Anchor discriminator
@@ -281,6 +302,73 @@ export function parseVoteAccount(address: PublicKey, data: Buffer): OnChainVote
     };
 }
 
+// ─── Pricing ───────────────────────────────────────────────────────────────
+// Mirrors `pricing::quote_cast_vote` in the program, so the quoted cost is
+// exactly what `cast_vote` charges and can be signed as `maxTotalCost`.
+
+const CURVE_FLAT = 0;
+const CURVE_LINEAR = 1;
+const CURVE_EXPONENTIAL = 2;
+const CURVE_QUADRATIC = 3;
+
+const ZERO = BigInt(0);
+const ONE = BigInt(1);
+const TWO = BigInt(2);
+/** Fixed-point scale for exponential growth factors (1e12) */
+const SCALE = BigInt(1_000_000_000_000);
+const BPS = BigInt(10_000);
+
+function divCeil(a: bigint, b: bigint): bigint {
+    return (a + b - ONE) / b;
+}
+
+/** `base^exp` in `SCALE` fixed point, rounded like `pricing::pow_scaled` */
+function powScaled(base: bigint, exp: bigint, roundUp: boolean): bigint {
+    const mul = (x: bigint, y: bigint) => (roundUp ? divCeil(x * y, SCALE) : (x * y) / SCALE);
+    let acc = SCALE;
+    while (exp > ZERO) {
+        if (exp % TWO === ONE) acc = mul(acc, base);
+        exp = exp / TWO;
+        if (exp > ZERO) base = mul(base, base);
+    }
+    return acc;
+}
+
+/**
+ * Lamports `cast_vote` will charge for `numCoins` coins on an option, given
+ * the voter's `held` coins on it (only quadratic curves depend on them).
+ */
+export function quoteCastVote(
+    poll: OnChainPoll,
+    optionIndex: number,
+    numCoins: number,
+    held: number
+): bigint {
+    if (numCoins <= 0) return ZERO;
+    const curve = poll.priceCurves[optionIndex] ?? { kind: CURVE_FLAT, param: ZERO };
+    const unitPrice = BigInt(poll.unitPrice);
+    const sold = BigInt(poll.voteCounts[optionIndex] ?? 0);
+    const n = BigInt(numCoins);
+    const base = n * unitPrice;
+
+    switch (curve.kind) {
+        case CURVE_FLAT:
+            return base;
+        case CURVE_LINEAR:
+            return base + ((n * (TWO * sold + n - ONE)) / TWO) * curve.param;
+        case CURVE_EXPONENTIAL: {
+            const growth = SCALE + (SCALE * curve.param) / BPS;
+            const from = powScaled(growth, sold, false);
+            const to = powScaled(growth, sold + n, true);
+            return divCeil((to - from) * unitPrice * BPS, curve.param * SCALE);
+        }
+        case CURVE_QUADRATIC:
+            return n * (TWO * BigInt(held) + n) * unitPrice;
+        default:
+            throw new Error(`Unknown price curve kind ${curve.kind}`);
+    }
+}
+
 // ─── Instruction Builders ──────────────────────────────────────────────────
 
 /** Build InitializeUser instruction */
@@ -301,6 +389,19 @@ export async function buildInitializeUserIx(
     });
 }
 
+// ─── Optional Accounts ─────────────────────────────────────────────────────
+// Anchor `Option<>` accounts must still occupy their slot in the key list;
+// passing the program ID marks one as omitted (None). SOL polls omit the
+// SPL-collateral accounts, and the UI never sends a referrer, receipt,
+// allowlist pass or gate token account.
+
+const OMITTED = { pubkey: PROGRAM_ID, isSigner: false, isWritable: false };
+
+/** `count` omitted optional-account slots */
+function omitted(count: number) {
+    return Array.from({ length: count }, () => OMITTED);
+}
+
 /** Build CreatePoll instruction */
 export async function buildCreatePollIx(
     creator: PublicKey,
@@ -340,6 +441,9 @@ export async function buildCreatePollIx(
             { pubkey: pollPDA, isSigner: false, isWritable: true },
             { pubkey: treasuryPDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            // collateral_mint, vault, creator_token_account, token_program,
+            // associated_token_program, referrer, platform_config
+            ...omitted(7),
         ],
         data,
     });
@@ -354,10 +458,12 @@ export async function buildEditPollIx(
     category: string,
     imageUrl: string,
     options: string[],
-    endTime: number | bigint
+    endTime: number | bigint,
+    creatorSeeded = false
 ): Promise<TransactionInstruction> {
     const disc = await ixDiscriminator("edit_poll");
     const [pollPDA] = getPollPDA(creator, pollId);
+    const [orderBookPDA] = getOrderBookPDA(pollPDA);
 
     const writer = new BorshWriter();
     writer.writeU64(pollId);
@@ -375,6 +481,11 @@ export async function buildEditPollIx(
         keys: [
             { pubkey: creator, isSigner: true, isWritable: true },
             { pubkey: pollPDA, isSigner: false, isWritable: true },
+            { pubkey: orderBookPDA, isSigner: false, isWritable: false },
+            // creator_position: only exists once the poll was seeded
+            creatorSeeded
+                ? { pubkey: getVotePDA(pollPDA, creator)[0], isSigner: false, isWritable: false }
+                : OMITTED,
         ],
         data,
     });
@@ -419,11 +530,13 @@ export async function buildAdminEditPollIx(
 /** Build DeletePoll instruction */
 export async function buildDeletePollIx(
     creator: PublicKey,
-    pollId: number | bigint
+    pollId: number | bigint,
+    creatorSeeded = false
 ): Promise<TransactionInstruction> {
     const disc = await ixDiscriminator("delete_poll");
     const [pollPDA] = getPollPDA(creator, pollId);
     const [treasuryPDA] = getTreasuryPDA(pollPDA);
+    const [orderBookPDA] = getOrderBookPDA(pollPDA);
 
     const writer = new BorshWriter();
     writer.writeU64(pollId);
@@ -437,6 +550,13 @@ export async function buildDeletePollIx(
             { pubkey: pollPDA, isSigner: false, isWritable: true },
             { pubkey: treasuryPDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: orderBookPDA, isSigner: false, isWritable: false },
+            // collateral_mint, vault, creator_token_account, token_program
+            ...omitted(4),
+            // creator_position: only exists once the poll was seeded
+            creatorSeeded
+                ? { pubkey: getVotePDA(pollPDA, creator)[0], isSigner: false, isWritable: true }
+                : OMITTED,
         ],
         data,
     });
@@ -448,7 +568,8 @@ export async function buildCastVoteIx(
     pollCreator: PublicKey,
     pollId: number | bigint,
     optionIndex: number,
-    numCoins: number | bigint
+    numCoins: number | bigint,
+    maxTotalCost: number | bigint
 ): Promise<TransactionInstruction> {
     const disc = await ixDiscriminator("cast_vote");
     const [userPDA] = getUserPDA(voter);
@@ -460,6 +581,7 @@ export async function buildCastVoteIx(
     writer.writeU64(pollId);
     writer.writeU8(optionIndex);
     writer.writeU64(numCoins);
+    writer.writeU64(maxTotalCost);
 
     const data = Buffer.concat([Buffer.from(disc), writer.toBuffer()]);
 
@@ -472,6 +594,10 @@ export async function buildCastVoteIx(
             { pubkey: treasuryPDA, isSigner: false, isWritable: true },
             { pubkey: votePDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            // collateral_mint, vault, voter_token_account, token_program,
+            // receipt_mint, receipt_token_account, receipt_token_program,
+            // associated_token_program, allowlist_pass, gate_token_account
+            ...omitted(10),
         ],
         data,
     });
@@ -500,6 +626,8 @@ export async function buildSettlePollIx(
             { pubkey: pollPDA, isSigner: false, isWritable: true },
             { pubkey: treasuryPDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            // collateral_mint, vault, creator_token_account, token_program
+            ...omitted(4),
         ],
         data,
     });
@@ -530,6 +658,8 @@ export async function buildAdminSettlePollIx(
             { pubkey: pollPDA, isSigner: false, isWritable: true },
             { pubkey: treasuryPDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            // collateral_mint, vault, creator_token_account, token_program
+            ...omitted(4),
         ],
         data,
     });
@@ -546,6 +676,7 @@ export async function buildClaimRewardIx(
     const [pollPDA] = getPollPDA(pollCreator, pollId);
     const [treasuryPDA] = getTreasuryPDA(pollPDA);
     const [votePDA] = getVotePDA(pollPDA, claimer);
+    const [orderBookPDA] = getOrderBookPDA(pollPDA);
 
     const writer = new BorshWriter();
     writer.writeU64(pollId);
@@ -561,6 +692,9 @@ export async function buildClaimRewardIx(
             { pubkey: treasuryPDA, isSigner: false, isWritable: true },
             { pubkey: votePDA, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: orderBookPDA, isSigner: false, isWritable: false },
+            // collateral_mint, vault, claimer_token_account, token_program
+            ...omitted(4),
         ],
         data,
     });
@@ -23,6 +23,7 @@ export {
   getUserPDA,
   getPollPDA,
   getTreasuryPDA,
+  getOrderBookPDA,
   getVotePDA,
   lamportsToSol,
   solToLamports,
@@ -45,12 +46,14 @@ export type {
   OnChainUser,
   OnChainPoll,
   OnChainVote,
+  OnChainPriceCurve,
 } from "./program.onchain";
 
 export {
   parseUserAccount,
   parsePollAccount,
   parseVoteAccount,
+  quoteCastVote,
   buildInitializeUserIx,
   buildCreatePollIx,
   buildEditPollIx,
@@ -56,7 +56,7 @@ export type DemoPoll = {
   creatorInvestmentLamports: number; // LAMPORTS invested by creator
   platformFeeLamports: number;      // LAMPORTS platform fee
   creatorRewardLamports: number;    // LAMPORTS creator reward
-  status: number;                // 0 = Active, 1 = Settled
+  status: number;                // 0 = Active, 1 = Settled, 2 = Voided
   winningOption: number;         // 255 = unset
   totalVoters: number;
   createdAt: number;
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::state::{AllowlistPass, PollAccount, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// `allowlist_root` of a poll anyone may vote on.
+pub const OPEN_POLL_ROOT: [u8; 32] = [0u8; 32];
+
+// Leaf and node hashes are domain-separated, so a proof can never pass an
+// inner node off as a leaf.
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Leaf for `voter`, allowed to hold at most `max_coins` coins on the poll
+/// in total (0 = no per-leaf cap): `keccak(0x00 || voter || max_coins_le)`.
+pub fn leaf(voter: &Pubkey, max_coins: u64) -> [u8; 32] {
+    keccak::hashv(&[LEAF_PREFIX, voter.as_ref(), &max_coins.to_le_bytes()]).to_bytes()
+}
+
+/// Parent of two nodes. Children are sorted first, so proofs carry no
+/// left/right flags: `keccak(0x01 || min(a, b) || max(a, b))`.
+pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    keccak::hashv(&[NODE_PREFIX, lo, hi]).to_bytes()
+}
+
+/// Whether `proof` links `leaf` to `root`.
+pub fn verify(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    proof.iter().fold(leaf, |acc, sibling| node(&acc, sibling)) == *root
+}
+
+/// Off-chain builder for allowlist trees: computes the root to publish with
+/// `set_allowlist_root` and each voter's proof for `verify_allowlist`.
+///
+/// An odd node at the end of a level is carried up unchanged (and adds no
+/// proof element), so any number of entries is supported.
+pub struct AllowlistTree {
+    /// levels[0] = leaves, last level = [root]
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl AllowlistTree {
+    /// Builds the tree over `(voter, max_coins)` entries, in order.
+    pub fn new(entries: &[(Pubkey, u64)]) -> Self {
+        let mut levels = vec![entries
+            .iter()
+            .map(|(voter, max_coins)| leaf(voter, *max_coins))
+            .collect::<Vec<_>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    /// Merkle root, or `OPEN_POLL_ROOT` for an empty list.
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.levels.len() - 1]
+            .first()
+            .copied()
+            .unwrap_or(OPEN_POLL_ROOT)
+    }
+
+    /// Proof for the entry at `index`, or `None` if out of range.
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+        let mut proof = Vec::new();
+        let mut i = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(i ^ 1) {
+                proof.push(*sibling);
+            }
+            i /= 2;
+        }
+        Some(proof)
+    }
+}
+
+/// Vote-time gate shared by every instruction that buys option-coins: on an
+/// allowlisted poll the voter must present an `AllowlistPass` for the poll's
+/// current root, and their total coins must stay within the pass's cap.
+/// Call after the purchase is recorded on `vote`.
+pub fn require_allowlisted(
+    poll: &PollAccount,
+    pass: &Option<Account<AllowlistPass>>,
+    vote: &VoteAccount,
+) -> Result<()> {
+    if poll.allowlist_root == OPEN_POLL_ROOT {
+        return Ok(());
+    }
+    let pass = pass.as_ref().ok_or(InstinctFiError::NotAllowlisted)?;
+    require!(pass.root == poll.allowlist_root, InstinctFiError::NotAllowlisted);
+
+    if pass.max_coins > 0 {
+        let mut total: u64 = 0;
+        for coins in &vote.votes_per_option {
+            total = total.checked_add(*coins).ok_or(InstinctFiError::Overflow)?;
+        }
+        require!(total <= pass.max_coins, InstinctFiError::AllowlistCoinCapExceeded);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(n: usize) -> Vec<(Pubkey, u64)> {
+        (0..n).map(|i| (Pubkey::new_unique(), i as u64 * 10)).collect()
+    }
+
+    #[test]
+    fn every_entry_proves_inclusion() {
+        // Odd sizes exercise the carried-up last node
+        for n in 1..=9 {
+            let list = entries(n);
+            let tree = AllowlistTree::new(&list);
+            let root = tree.root();
+            for (i, (voter, max_coins)) in list.iter().enumerate() {
+                let proof = tree.proof(i).unwrap();
+                assert!(verify(&root, leaf(voter, *max_coins), &proof), "n={} i={}", n, i);
+            }
+        }
+    }
+
+    #[test]
+    fn outsiders_and_altered_leaves_are_rejected() {
+        let list = entries(5);
+        let tree = AllowlistTree::new(&list);
+        let root = tree.root();
+        let (voter, max_coins) = list[2];
+        let proof = tree.proof(2).unwrap();
+
+        // Another wallet with a member's proof
+        assert!(!verify(&root, leaf(&Pubkey::new_unique(), max_coins), &proof));
+        // A member claiming a larger cap
+        assert!(!verify(&root, leaf(&voter, max_coins + 1), &proof));
+        // A member presenting someone else's proof
+        assert!(!verify(&root, leaf(&voter, max_coins), &tree.proof(3).unwrap()));
+        // A tampered or truncated proof
+        let mut tampered = proof.clone();
+        tampered[0][0] ^= 1;
+        assert!(!verify(&root, leaf(&voter, max_coins), &tampered));
+        assert!(!verify(&root, leaf(&voter, max_coins), &proof[1..]));
+        // A valid proof against another list's root
+        assert!(!verify(&AllowlistTree::new(&entries(5)).root(), leaf(&voter, max_coins), &proof));
+    }
+
+    #[test]
+    fn empty_list_and_out_of_range() {
+        let empty = AllowlistTree::new(&[]);
+        assert_eq!(empty.root(), OPEN_POLL_ROOT);
+        assert!(empty.proof(0).is_none());
+
+        let tree = AllowlistTree::new(&entries(3));
+        assert!(tree.proof(3).is_none());
+        assert_eq!(tree.proof(0).unwrap().len(), 2);
+        // The carried-up third leaf needs only one sibling
+        assert_eq!(tree.proof(2).unwrap().len(), 1);
+    }
+}
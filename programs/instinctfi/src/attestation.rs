@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Attestation, AttestationIssuer, IdentityVote, RegionTally, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// The accounts an attestation-gated instruction hands to `require_attested`.
+pub struct AttestationAccounts<'a, 'info> {
+    pub voter: &'a Signer<'info>,
+    pub attestation: &'a Option<Account<'info, Attestation>>,
+    pub issuer: &'a Option<Account<'info, AttestationIssuer>>,
+    pub identity_vote: &'a Option<UncheckedAccount<'info>>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Vote-time check shared by the civic ballot instructions and
+/// `answer_survey`. When `required` (the poll's or survey's
+/// `require_attestation`) the voter must present their `Attestation` (PDA
+/// `["attestation", voter]`), unexpired and from an issuer that is still
+/// whitelisted, plus the uninitialized `IdentityVote` PDA
+/// `["identity_vote", poll_key, identity_hash]`, which is created here. A second
+/// wallet attested to the same identity derives the same PDA and is
+/// rejected, so each person votes once per poll however many wallets they
+/// hold.
+///
+/// Returns the attestation's region, or `None` when the poll needs no
+/// attestation.
+pub fn require_attested(
+    required: bool,
+    poll_key: &Pubkey,
+    accounts: AttestationAccounts,
+    now: i64,
+) -> Result<Option<u16>> {
+    if !required {
+        return Ok(None);
+    }
+    let AttestationAccounts { voter, attestation, issuer, identity_vote, system_program } = accounts;
+    let attestation = attestation.as_ref().ok_or(InstinctFiError::AttestationRequired)?;
+    require_keys_eq!(attestation.wallet, voter.key(), InstinctFiError::AttestationRequired);
+    require!(now < attestation.expires_at, InstinctFiError::AttestationExpired);
+    let issuer = issuer.as_ref().ok_or(InstinctFiError::UnknownAttestationIssuer)?;
+    require_keys_eq!(issuer.issuer, attestation.issuer, InstinctFiError::UnknownAttestationIssuer);
+
+    // ── Claim the identity for this poll ──
+    let identity_vote = identity_vote.as_ref().ok_or(InstinctFiError::InvalidIdentityVote)?;
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"identity_vote", poll_key.as_ref(), attestation.identity_hash.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(identity_vote.key(), expected, InstinctFiError::InvalidIdentityVote);
+    require!(
+        identity_vote.owner != &crate::ID,
+        InstinctFiError::IdentityAlreadyVoted
+    );
+
+    let seeds: &[&[u8]] = &[
+        b"identity_vote",
+        poll_key.as_ref(),
+        attestation.identity_hash.as_ref(),
+        &[bump],
+    ];
+    let space = 8 + IdentityVote::INIT_SPACE;
+    create_pda(
+        &voter.to_account_info(),
+        &identity_vote.to_account_info(),
+        &system_program.to_account_info(),
+        Rent::get()?.minimum_balance(space),
+        space,
+        &crate::ID,
+        seeds,
+    )?;
+    let record = IdentityVote {
+        poll: *poll_key,
+        identity_hash: attestation.identity_hash,
+        voter: voter.key(),
+        region: attestation.region,
+        bump,
+    };
+    let mut data = identity_vote.try_borrow_mut_data()?;
+    record.try_serialize(&mut &mut data[..])?;
+    Ok(Some(attestation.region))
+}
+
+/// Adds a recorded ballot to its region's `RegionTally` (from
+/// `init_region_tally`). Every attestation-gated vote must pass its region's
+/// tally, so the regional breakdown always sums to the global tally.
+pub fn record_regional_vote(
+    poll_key: &Pubkey,
+    region_tally: &mut Option<Account<RegionTally>>,
+    region: Option<u16>,
+    vote: &VoteAccount,
+) -> Result<()> {
+    let Some(region) = region else {
+        return Ok(());
+    };
+    let tally = region_tally.as_mut().ok_or(InstinctFiError::InvalidRegionTally)?;
+    require!(
+        tally.poll == *poll_key && tally.region == region,
+        InstinctFiError::InvalidRegionTally
+    );
+
+    for (count, votes) in tally.vote_counts.iter_mut().zip(&vote.votes_per_option) {
+        *count = count.checked_add(*votes).ok_or(InstinctFiError::Overflow)?;
+    }
+    tally.voters = tally.voters.checked_add(1).ok_or(InstinctFiError::Overflow)?;
+    Ok(())
+}
+
+/// Creates a PDA of `space` bytes owned by `owner`, funded by `payer` up to
+/// `lamports`. Like Anchor's `init`, it tolerates lamports sent to the
+/// address beforehand, so a pre-funded address cannot block its creation
+/// (used for `IdentityVote` records and receipt mints).
+pub(crate) fn create_pda<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    lamports: u64,
+    space: usize,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let current = target.lamports();
+
+    if current == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    if current < lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            lamports - current,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: target.clone() },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: target.clone() },
+            &[seeds],
+        ),
+        owner,
+    )
+}
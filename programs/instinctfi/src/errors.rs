@@ -64,4 +64,148 @@ pub enum InstinctFiError {
     NotATie,
     #[msg("Admin grace period active — only platform admin can settle within 7 days of poll end")]
     AdminGracePeriodActive,
+    #[msg("Outcome token accounts do not match the poll's options")]
+    InvalidOutcomeAccounts,
+    #[msg("Poll has outstanding outcome tokens backed by the treasury")]
+    OutstandingOutcomeTokens,
+    #[msg("Invalid order (side, price or quantity)")]
+    InvalidOrder,
+    #[msg("Order book is full")]
+    OrderBookFull,
+    #[msg("Order not found")]
+    OrderNotFound,
+    #[msg("Not enough coins on this option to sell")]
+    InsufficientPosition,
+    #[msg("Invalid price curve")]
+    InvalidPriceCurve,
+    #[msg("Total cost exceeds the maximum the voter signed for")]
+    SlippageExceeded,
+    #[msg("Collateral mint does not match the poll")]
+    CollateralMismatch,
+    #[msg("SPL-denominated poll requires mint, vault, token account and token program")]
+    MissingCollateralAccounts,
+    #[msg("Collateral mint has Token-2022 extensions that are not supported")]
+    UnsupportedCollateralMint,
+    #[msg("Not supported for SPL-denominated polls")]
+    UnsupportedForTokenPolls,
+    #[msg("Receipt requires mint, token account, Token-2022 and associated token programs")]
+    MissingReceiptAccounts,
+    #[msg("Vote record still has a claimable position")]
+    VoteRecordOpen,
+    #[msg("Referral share cannot exceed 10000 bps")]
+    InvalidReferralShare,
+    #[msg("Referrals require the platform config account")]
+    MissingPlatformConfig,
+    #[msg("Cannot refer yourself")]
+    SelfReferral,
+    #[msg("No referral rewards to withdraw")]
+    NothingToWithdraw,
+    #[msg("Vote would exceed the poll's max stake per voter")]
+    VoterStakeCapExceeded,
+    #[msg("Vote would exceed the poll's max coins per option for this voter")]
+    OptionCoinCapExceeded,
+    #[msg("Poll met its liquidity thresholds and cannot be voided")]
+    LiquidityThresholdsMet,
+    #[msg("Poll has not been voided")]
+    NotVoided,
+    #[msg("Seeded coins cost more than the creator's pool seed")]
+    SeedExceedsPool,
+    #[msg("Memo exceeds 64 characters")]
+    MemoTooLong,
+    #[msg("Sponsorship is only refundable once the poll is voided, deleted or settled without votes")]
+    SponsorNotRefundable,
+    #[msg("Session has expired")]
+    SessionExpired,
+    #[msg("Session expiry must be in the future")]
+    InvalidSessionExpiry,
+    #[msg("Poll is outside the session's scope")]
+    SessionOutOfScope,
+    #[msg("Vote would exceed the session's spend cap")]
+    SessionSpendCapExceeded,
+    #[msg("Session escrow balance too low")]
+    EscrowInsufficient,
+    #[msg("Missing or invalid ed25519 signature for the vote intent")]
+    InvalidRelaySignature,
+    #[msg("Vote intent has expired")]
+    IntentExpired,
+    #[msg("Vote intent does not match this poll")]
+    IntentPollMismatch,
+    #[msg("Vote intent nonce already used or out of order")]
+    InvalidNonce,
+    #[msg("Voter is not on this poll's allowlist — submit a proof with verify_allowlist")]
+    NotAllowlisted,
+    #[msg("Invalid allowlist merkle proof")]
+    InvalidAllowlistProof,
+    #[msg("Vote would exceed the voter's allowlist coin cap")]
+    AllowlistCoinCapExceeded,
+    #[msg("Voter must hold the poll's gate token — pass a gate token account they own")]
+    TokenGateNotMet,
+    #[msg("Civic polls have no stakes or rewards — use cast_civic_vote")]
+    NotAMarketPoll,
+    #[msg("Not a civic poll")]
+    NotACivicPoll,
+    #[msg("Civic polls must be gated by an allowlist or issuer attestations")]
+    CivicPollNotGated,
+    #[msg("Quadratic-priced coins cannot be traded on the order book")]
+    QuadraticCoinsNotTradable,
+    #[msg("Not a ranked-choice poll")]
+    NotARankedPoll,
+    #[msg("Ranking must list distinct, valid options")]
+    InvalidRanking,
+    #[msg("Ranked tally is already complete")]
+    TallyComplete,
+    #[msg("Account is not a ballot of this poll")]
+    InvalidBallot,
+    #[msg("Not an approval-voting poll")]
+    NotAnApprovalPoll,
+    #[msg("Approvals must name at least one valid option")]
+    InvalidApprovals,
+    #[msg("Attestation issuer is not whitelisted")]
+    UnknownAttestationIssuer,
+    #[msg("Attestation expiry must be in the future")]
+    InvalidAttestationExpiry,
+    #[msg("Poll requires a valid attestation for the voter's wallet")]
+    AttestationRequired,
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+    #[msg("Identity vote account does not match the attested identity")]
+    InvalidIdentityVote,
+    #[msg("This identity has already voted on the poll")]
+    IdentityAlreadyVoted,
+    #[msg("Pass the RegionTally of the voter's attestation region for this poll")]
+    InvalidRegionTally,
+    #[msg("Question prompt exceeds 128 characters")]
+    PromptTooLong,
+    #[msg("Survey question limit reached")]
+    TooManyQuestions,
+    #[msg("Questions can only be added before the survey's first response")]
+    SurveyHasResponses,
+    #[msg("Survey has no questions")]
+    SurveyHasNoQuestions,
+    #[msg("Answers must cover every question with a valid option (255 = skip)")]
+    InvalidSurveyAnswers,
+    #[msg("Account is not the expected question of this survey")]
+    InvalidSurveyQuestion,
+    #[msg("Survey has ended")]
+    SurveyEnded,
+    #[msg("Voting has not opened yet")]
+    PollNotStarted,
+    #[msg("Start time must be before the poll's end time")]
+    InvalidStartTime,
+    #[msg("Soft close needs a threshold of 1–10000 bps, a non-zero extension and at least one extension")]
+    InvalidSoftClose,
+    #[msg("Poll has sponsorships — settle or void it so sponsors can be refunded")]
+    PollHasSponsors,
+    #[msg("Order book is unavailable on polls with stake caps, an allowlist or a token gate")]
+    OrderBookUnavailable,
+    #[msg("Order is below the minimum order size")]
+    OrderTooSmall,
+    #[msg("Open orders must be cancelled first")]
+    OpenOrders,
+    #[msg("Voided poll still has voters awaiting refunds")]
+    RefundsOutstanding,
+    #[msg("Seeded poll requires the creator position account")]
+    MissingCreatorPosition,
+    #[msg("Not a PollAccount or VoteAccount in its original layout")]
+    InvalidMigration,
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// One region's results of a finished civic poll — see
+/// `emit_regional_results`. `winning_option` is the poll-wide outcome.
+#[event]
+pub struct RegionalResults {
+    pub poll: Pubkey,
+    pub poll_id: u64,
+    pub region: u16,
+    pub vote_counts: Vec<u64>,
+    pub voters: u64,
+    pub winning_option: u8,
+}
+
+/// Enough late stake pushed a poll's `end_time` back — see
+/// `cast_vote::apply_soft_close`.
+#[event]
+pub struct EndTimeExtended {
+    pub poll: Pubkey,
+    pub poll_id: u64,
+    pub voter: Pubkey,
+    /// Stake accumulated in the close window, triggering vote included
+    pub window_stake: u64,
+    /// `total_pool` when the close window opened
+    pub window_base: u64,
+    pub previous_end_time: i64,
+    pub new_end_time: i64,
+    /// 1-based count of this extension
+    pub extension: u8,
+}
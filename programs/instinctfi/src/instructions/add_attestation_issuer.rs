@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::{AttestationIssuer, PLATFORM_ADMIN};
+use crate::errors::InstinctFiError;
+
+/// Whitelist an identity provider whose `issue_attestation` signatures
+/// civic polls accept. Only PLATFORM_ADMIN may call this.
+pub fn handler(ctx: Context<AddAttestationIssuer>, issuer: Pubkey) -> Result<()> {
+    let account = &mut ctx.accounts.issuer_account;
+    account.issuer = issuer;
+    account.added_at = Clock::get()?.unix_timestamp;
+    account.bump = ctx.bumps.issuer_account;
+
+    msg!("Attestation issuer {} added", issuer);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(issuer: Pubkey)]
+pub struct AddAttestationIssuer<'info> {
+    /// The platform admin — ONLY this wallet can whitelist issuers.
+    #[account(
+        mut,
+        constraint = admin.key() == PLATFORM_ADMIN @ InstinctFiError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    /// Issuer whitelist entry PDA
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AttestationIssuer::INIT_SPACE,
+        seeds = [b"issuer", issuer.as_ref()],
+        bump,
+    )]
+    pub issuer_account: Account<'info, AttestationIssuer>,
+
+    pub system_program: Program<'info, System>,
+}
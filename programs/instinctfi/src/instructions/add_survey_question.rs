@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{Survey, SurveyQuestion};
+use crate::errors::InstinctFiError;
+
+/// Append the next question to a survey (creator only). Questions are
+/// numbered in the order they are added and can only be added before the
+/// first response and before the survey ends.
+pub fn handler(
+    ctx: Context<AddSurveyQuestion>,
+    _survey_id: u64,
+    prompt: String,
+    options: Vec<String>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let survey = &mut ctx.accounts.survey;
+
+    require!(prompt.len() <= 128, InstinctFiError::PromptTooLong);
+    require!(options.len() >= 2 && options.len() <= 6, InstinctFiError::InvalidOptionCount);
+    for opt in &options {
+        require!(opt.len() <= 32, InstinctFiError::OptionLabelTooLong);
+    }
+    require!(survey.num_questions < Survey::MAX_QUESTIONS, InstinctFiError::TooManyQuestions);
+    require!(survey.total_participants == 0, InstinctFiError::SurveyHasResponses);
+    require!(!survey.is_ended(&clock), InstinctFiError::SurveyEnded);
+
+    let question = &mut ctx.accounts.question;
+    question.survey = survey.key();
+    question.index = survey.num_questions;
+    question.prompt = prompt;
+    question.vote_counts = vec![0u64; options.len()];
+    question.options = options;
+    question.bump = ctx.bumps.question;
+
+    survey.num_questions += 1;
+
+    msg!(
+        "Survey {}: question {} added with {} options",
+        survey.survey_id,
+        question.index,
+        question.options.len()
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(survey_id: u64)]
+pub struct AddSurveyQuestion<'info> {
+    /// Survey creator (pays question rent)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"survey", creator.key().as_ref(), &survey_id.to_le_bytes()],
+        bump = survey.bump,
+        has_one = creator @ InstinctFiError::Unauthorized,
+    )]
+    pub survey: Account<'info, Survey>,
+
+    /// Question PDA at the next free index
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + SurveyQuestion::INIT_SPACE,
+        seeds = [b"survey_question", survey.key().as_ref(), &[survey.num_questions]],
+        bump,
+    )]
+    pub question: Account<'info, SurveyQuestion>,
+
+    pub system_program: Program<'info, System>,
+}
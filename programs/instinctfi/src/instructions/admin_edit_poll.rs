@@ -13,7 +13,7 @@ use crate::errors::InstinctFiError;
 ///
 /// Safety constraints still enforced:
 /// - Cannot edit a settled poll (funds already distributed).
-/// - end_time must be in the future.
+/// - end_time must be in the future and after start_time.
 /// - Option count must match (can't add/remove options after creation).
 /// - Input length limits are enforced.
 pub fn handler(
@@ -45,6 +45,7 @@ pub fn handler(
         require!(opt.len() <= 32, InstinctFiError::OptionLabelTooLong);
     }
     require!(end_time > clock.unix_timestamp, InstinctFiError::EndTimeInPast);
+    require!(end_time > poll.start_time, InstinctFiError::InvalidStartTime);
 
     // ── Apply edits ──
     poll.title = title;
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::{PollAccount, PLATFORM_ADMIN};
 use crate::errors::InstinctFiError;
+use crate::vault;
+use super::void_poll::void_poll;
 
 /// Admin-settle a prediction market poll by declaring the real-world outcome.
 ///
@@ -18,6 +20,8 @@ use crate::errors::InstinctFiError;
 ///
 /// If no votes were placed on the winning option, the creator_reward is still
 /// paid to the creator, and the remaining pool stays in treasury until swept.
+/// A poll that missed its liquidity thresholds is voided instead (as by
+/// `void_poll`).
 pub fn handler(
     ctx: Context<AdminSettlePoll>,
     _poll_id: u64,
@@ -31,35 +35,60 @@ pub fn handler(
     let status = ctx.accounts.poll_account.status;
     let end_time = ctx.accounts.poll_account.end_time;
     let creator_reward = ctx.accounts.poll_account.creator_reward;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+    let total_sponsored = ctx.accounts.poll_account.total_sponsored;
     let options_len = ctx.accounts.poll_account.options.len();
     let vote_counts = ctx.accounts.poll_account.vote_counts.clone();
     let poll_id_val = ctx.accounts.poll_account.poll_id;
 
     // ── Guards ──
     require!(status == PollAccount::STATUS_ACTIVE, InstinctFiError::AlreadySettled);
+    require!(!ctx.accounts.poll_account.is_civic(), InstinctFiError::NotAMarketPoll);
     require!(clock.unix_timestamp >= end_time, InstinctFiError::PollNotEnded);
     require!((winning_option as usize) < options_len, InstinctFiError::InvalidOption);
 
-    // ── PDA signer seeds for treasury ──
-    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
-    let signer_seeds = &[seeds];
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.token_program,
+    )?;
+
+    // ── Missed its liquidity thresholds: void instead of settling ──
+    if !ctx.accounts.poll_account.meets_liquidity_thresholds() {
+        return void_poll(
+            &mut ctx.accounts.poll_account,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            token.as_ref(),
+        );
+    }
 
     // ── Check if any votes were cast at all ──
     let total_votes: u64 = vote_counts.iter().sum();
 
     if total_votes == 0 {
         // No votes at all — refund entire treasury to creator
-        let treasury_balance = ctx.accounts.treasury.lamports();
+        // Outcome-token collateral and sponsored funds (plus SOL rent while
+        // any remain) stay behind for redemptions and `refund_sponsor`.
+        let held = set_collateral.saturating_add(total_sponsored);
+        let reserved = match (held, token.as_ref()) {
+            (0, _) => 0,
+            (_, None) => held.saturating_add(Rent::get()?.minimum_balance(0)),
+            (_, Some(_)) => held,
+        };
+        let treasury_balance = vault::balance(&ctx.accounts.treasury, token.as_ref())
+            .saturating_sub(reserved);
         if treasury_balance > 0 {
-            system_program::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    system_program::Transfer {
-                        from: ctx.accounts.treasury.to_account_info(),
-                        to: ctx.accounts.creator.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
+            vault::withdraw(
+                &poll_key,
+                treasury_bump,
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                token.as_ref(),
                 treasury_balance,
             )?;
         }
@@ -77,24 +106,23 @@ pub fn handler(
 
     // ── Pay creator reward ──
     if creator_reward > 0 {
-        let rent = Rent::get()?;
-        let rent_exempt_min = rent.minimum_balance(0);
-        let treasury_available = ctx.accounts.treasury.lamports()
-            .saturating_sub(rent_exempt_min);
+        let treasury_available = vault::available(
+            &ctx.accounts.treasury,
+            token.as_ref(),
+            set_collateral,
+        )?;
         require!(
             treasury_available >= creator_reward,
             InstinctFiError::TreasuryInsufficient
         );
 
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.treasury.to_account_info(),
-                    to: ctx.accounts.creator.to_account_info(),
-                },
-                signer_seeds,
-            ),
+        vault::withdraw(
+            &poll_key,
+            treasury_bump,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            token.as_ref(),
             creator_reward,
         )?;
     }
@@ -152,4 +180,28 @@ pub struct AdminSettlePoll<'info> {
     pub treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: creator's token account receiving refunds and the creator reward
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
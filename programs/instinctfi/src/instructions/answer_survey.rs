@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use crate::state::{Attestation, AttestationIssuer, Survey, SurveyQuestion, SurveyResponse};
+use crate::errors::InstinctFiError;
+use crate::attestation;
+
+/// Answer every question of a survey in one transaction.
+///
+/// `answers[i]` is the chosen option of question `i`, or
+/// `SurveyResponse::ANSWER_SKIPPED`; at least one question must be
+/// answered. Pass all of the survey's SurveyQuestion accounts (writable, in
+/// index order) as remaining accounts. The SurveyResponse is created with
+/// `init`, so each wallet responds once; attestation-gated surveys also
+/// claim the identity's IdentityVote, as civic polls do.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AnswerSurvey<'info>>,
+    _survey_id: u64,
+    answers: Vec<u8>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let survey_key = ctx.accounts.survey.key();
+    let voter_key = ctx.accounts.voter.key();
+
+    // ── Guards ──
+    let survey = &ctx.accounts.survey;
+    require!(!survey.is_ended(&clock), InstinctFiError::SurveyEnded);
+    require!(survey.num_questions > 0, InstinctFiError::SurveyHasNoQuestions);
+    require!(
+        answers.len() == survey.num_questions as usize
+            && ctx.remaining_accounts.len() == answers.len()
+            && answers.iter().any(|&a| a != SurveyResponse::ANSWER_SKIPPED),
+        InstinctFiError::InvalidSurveyAnswers
+    );
+
+    attestation::require_attested(
+        survey.require_attestation,
+        &survey_key,
+        attestation::AttestationAccounts {
+            voter: &ctx.accounts.voter,
+            attestation: &ctx.accounts.attestation,
+            issuer: &ctx.accounts.attestation_issuer,
+            identity_vote: &ctx.accounts.identity_vote,
+            system_program: &ctx.accounts.system_program,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    // ── Tally each answer on its question ──
+    for (i, (info, &answer)) in ctx.remaining_accounts.iter().zip(&answers).enumerate() {
+        require_keys_eq!(*info.owner, crate::ID, InstinctFiError::InvalidSurveyQuestion);
+        require!(info.is_writable, InstinctFiError::InvalidSurveyQuestion);
+        let mut question = SurveyQuestion::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            question.survey == survey_key && question.index as usize == i,
+            InstinctFiError::InvalidSurveyQuestion
+        );
+        if answer == SurveyResponse::ANSWER_SKIPPED {
+            continue;
+        }
+        require!(
+            (answer as usize) < question.options.len(),
+            InstinctFiError::InvalidSurveyAnswers
+        );
+        let idx = answer as usize;
+        question.vote_counts[idx] = question.vote_counts[idx]
+            .checked_add(1)
+            .ok_or(InstinctFiError::Overflow)?;
+        question.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    }
+
+    // ── Record the participant ──
+    let response = &mut ctx.accounts.response;
+    response.survey = survey_key;
+    response.voter = voter_key;
+    response.answers = answers;
+    response.answered_at = clock.unix_timestamp;
+    response.bump = ctx.bumps.response;
+
+    let survey = &mut ctx.accounts.survey;
+    survey.total_participants = survey.total_participants
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!(
+        "Survey {}: {} answered {:?}",
+        survey.survey_id,
+        voter_key,
+        response.answers
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(survey_id: u64)]
+pub struct AnswerSurvey<'info> {
+    /// Respondent (pays SurveyResponse rent only)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"survey", survey.creator.as_ref(), &survey_id.to_le_bytes()],
+        bump = survey.bump,
+    )]
+    pub survey: Account<'info, Survey>,
+
+    /// Participant record — `init`, so each wallet responds once
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + SurveyResponse::INIT_SPACE,
+        seeds = [b"survey_response", survey.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub response: Account<'info, SurveyResponse>,
+
+    /// The voter's Attestation (attestation-gated surveys)
+    #[account(
+        seeds = [b"attestation", voter.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Whitelist entry of the attestation's issuer
+    pub attestation_issuer: Option<Account<'info, AttestationIssuer>>,
+
+    /// CHECK: IdentityVote PDA ["identity_vote", survey, identity_hash] —
+    /// verified and created by `attestation::require_attested`
+    #[account(mut)]
+    pub identity_vote: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
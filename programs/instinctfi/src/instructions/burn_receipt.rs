@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{self, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::{PollAccount, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// Burn the voter's participation receipt.
+///
+/// Allowed once the vote record has nothing left to collect: it has been
+/// claimed (`claim_reward` / `refund_tied_poll`), or the poll is settled and
+/// the voter holds no coins on the winning option. The receipt token is
+/// burned and its token account and mint are closed, returning their rent to
+/// the voter. The VoteAccount stays open: closing it would let the
+/// `init_if_needed` paths (`cast_vote`, `cancel_order`, ...) recreate it
+/// fresh and unclaimed.
+pub fn handler(ctx: Context<BurnReceipt>, _poll_id: u64) -> Result<()> {
+    let poll_key = ctx.accounts.poll_account.key();
+    let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let status = ctx.accounts.poll_account.status;
+    let winning_option = ctx.accounts.poll_account.winning_option;
+    let vote = &ctx.accounts.vote_account;
+
+    // ── Guards ──
+    let settled_without_winnings = status == PollAccount::STATUS_SETTLED
+        && winning_option != 255
+        && vote.votes_per_option[winning_option as usize] == 0;
+    require!(
+        vote.claimed || settled_without_winnings,
+        InstinctFiError::VoteRecordOpen
+    );
+
+    // ── Burn the receipt and close its token account ──
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::Burn {
+                mint: ctx.accounts.receipt_mint.to_account_info(),
+                from: ctx.accounts.receipt_token_account.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+    token_2022::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token_2022::CloseAccount {
+            account: ctx.accounts.receipt_token_account.to_account_info(),
+            destination: ctx.accounts.voter.to_account_info(),
+            authority: ctx.accounts.voter.to_account_info(),
+        },
+    ))?;
+
+    // ── Close the mint (treasury PDA is its close authority) ──
+    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
+    let signer_seeds = &[seeds];
+
+    token_2022::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token_2022::CloseAccount {
+            account: ctx.accounts.receipt_mint.to_account_info(),
+            destination: ctx.accounts.voter.to_account_info(),
+            authority: ctx.accounts.treasury.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!(
+        "BurnReceipt: voter {} burned receipt for poll {}",
+        ctx.accounts.voter.key(),
+        ctx.accounts.poll_account.poll_id
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct BurnReceipt<'info> {
+    /// Voter holding the receipt — receives all reclaimed rent
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// The poll the receipt belongs to
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — receipt mint close authority
+    #[account(
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Vote record the receipt was issued for (kept open)
+    #[account(
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = vote_account.bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    /// Receipt mint PDA
+    #[account(
+        mut,
+        seeds = [b"receipt", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+        mint::token_program = token_program,
+    )]
+    pub receipt_mint: InterfaceAccount<'info, Mint>,
+
+    /// Voter's receipt token account
+    #[account(
+        mut,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = voter,
+        associated_token::token_program = token_program,
+    )]
+    pub receipt_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Order, OrderBook, PollAccount, VoteAccount};
+use crate::errors::InstinctFiError;
+use super::cast_vote::init_vote_record;
+
+/// Close an order and collect everything it holds.
+///
+/// Bid: unspent escrow lamports are refunded; filled coins and their stake
+/// basis are credited to the owner's VoteAccount (created if needed).
+/// Ask: proceeds are paid out; unfilled coins and their remaining stake
+/// basis return to the owner's VoteAccount.
+///
+/// Works at any time, including after settlement. Claims and refunds wait
+/// until the voter's orders are collected here, so a vote record is never
+/// marked claimed while coins are still in the book.
+pub fn handler(ctx: Context<CancelOrder>, _poll_id: u64, order_id: u64) -> Result<()> {
+    let owner_key = ctx.accounts.owner.key();
+    let poll_key = ctx.accounts.poll_account.key();
+    let escrow_bump = ctx.accounts.order_book.escrow_bump;
+
+    // ── Remove order from book ──
+    let book = &mut ctx.accounts.order_book;
+    let pos = book
+        .orders
+        .iter()
+        .position(|o| o.order_id == order_id)
+        .ok_or(InstinctFiError::OrderNotFound)?;
+    require_keys_eq!(book.orders[pos].owner, owner_key, InstinctFiError::Unauthorized);
+    let order = book.orders.swap_remove(pos);
+
+    // ── Position returned to / delivered into the VoteAccount ──
+    let coins = order_coins(&order);
+    if coins > 0 || order.escrow_stake > 0 {
+        init_vote_record(
+            &mut ctx.accounts.poll_account,
+            &mut ctx.accounts.vote_account,
+            poll_key,
+            owner_key,
+            ctx.bumps.vote_account,
+        )?;
+        credit_position(&ctx.accounts.poll_account, &mut ctx.accounts.vote_account, &order)?;
+    }
+
+    // ── Lamports: escrow PDA → owner ──
+    if order.escrow_lamports > 0 {
+        let seeds: &[&[u8]] = &[b"order_escrow", poll_key.as_ref(), &[escrow_bump]];
+        let signer_seeds = &[seeds];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.escrow.to_account_info(),
+                    to: ctx.accounts.owner.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            order.escrow_lamports,
+        )?;
+    }
+
+    msg!(
+        "CancelOrder: poll={} order={} coins={} lamports={}",
+        ctx.accounts.poll_account.poll_id,
+        order_id,
+        coins,
+        order.escrow_lamports
+    );
+    Ok(())
+}
+
+/// Coins a closed order hands back: the fills of a bid, the unsold rest of
+/// an ask.
+pub(crate) fn order_coins(order: &Order) -> u64 {
+    if order.side == Order::SIDE_BID { order.filled } else { order.remaining }
+}
+
+/// Credits a closed order's coins and stake basis to its owner's vote
+/// record. Bid fills are the only way coins reach a new holder, so they get
+/// the creator-cannot-vote check `cast_vote` applies.
+pub(crate) fn credit_position(poll: &PollAccount, vote: &mut VoteAccount, order: &Order) -> Result<()> {
+    require!(!vote.claimed, InstinctFiError::AlreadyClaimed);
+    require!(
+        order.side == Order::SIDE_ASK || order.filled == 0 || order.owner != poll.creator,
+        InstinctFiError::CreatorCannotVote
+    );
+
+    let idx = order.option_index as usize;
+    vote.votes_per_option[idx] = vote.votes_per_option[idx]
+        .checked_add(order_coins(order))
+        .ok_or(InstinctFiError::Overflow)?;
+    vote.total_staked = vote.total_staked
+        .checked_add(order.escrow_stake)
+        .ok_or(InstinctFiError::Overflow)?;
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CancelOrder<'info> {
+    /// Order owner
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The poll being traded
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// The poll's order book
+    #[account(
+        mut,
+        seeds = [b"order_book", poll_account.key().as_ref()],
+        bump = order_book.bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    /// CHECK: Escrow PDA — source of refunds and proceeds
+    #[account(
+        mut,
+        seeds = [b"order_escrow", poll_account.key().as_ref()],
+        bump = order_book.escrow_bump,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Owner's vote record — receives filled or unsold coins
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    pub system_program: Program<'info, System>,
+}
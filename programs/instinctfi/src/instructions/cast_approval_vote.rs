@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistPass, Attestation, AttestationIssuer, PollAccount, RegionTally, VoteAccount};
+use crate::errors::InstinctFiError;
+use crate::{allowlist, attestation};
+use super::cast_vote::init_vote_record;
+
+/// Cast the voter's single, free approval ballot on an approval poll.
+///
+/// `approvals` is a bitmask of approved options (bit i = option i); at least
+/// one bit must be set and none beyond the poll's options. Each approved
+/// option gets one vote — identities are weighted equally — recorded in
+/// `votes_per_option` and `vote_counts`. The VoteAccount is created with
+/// `init`, so each identity votes once. Eligibility is checked as in
+/// `cast_civic_vote` (AllowlistPass and/or Attestation);
+/// `finalize_civic_poll` picks the most-approved option.
+pub fn handler(ctx: Context<CastApprovalVote>, _poll_id: u64, approvals: u8) -> Result<()> {
+    let clock = Clock::get()?;
+    let voter_key = ctx.accounts.voter.key();
+    let poll_key = ctx.accounts.poll_account.key();
+
+    // ── Guards ──
+    let poll = &ctx.accounts.poll_account;
+    require!(
+        poll.poll_kind == PollAccount::KIND_APPROVAL,
+        InstinctFiError::NotAnApprovalPoll
+    );
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+    require!(poll.has_started(&clock), InstinctFiError::PollNotStarted);
+    require!(!poll.is_ended(&clock), InstinctFiError::PollAlreadyEnded);
+    require!(
+        poll.allowlist_root != allowlist::OPEN_POLL_ROOT || poll.require_attestation,
+        InstinctFiError::CivicPollNotGated
+    );
+    let valid_bits = ((1u16 << poll.options.len()) - 1) as u8;
+    require!(
+        approvals != 0 && approvals & !valid_bits == 0,
+        InstinctFiError::InvalidApprovals
+    );
+
+    // ── Record one vote per approved option ──
+    let poll = &mut ctx.accounts.poll_account;
+    let vote_account = &mut ctx.accounts.vote_account;
+    init_vote_record(poll, vote_account, poll_key, voter_key, ctx.bumps.vote_account)?;
+    for i in 0..poll.options.len() {
+        if approvals & (1 << i) != 0 {
+            vote_account.votes_per_option[i] = 1;
+            poll.vote_counts[i] = poll.vote_counts[i]
+                .checked_add(1)
+                .ok_or(InstinctFiError::Overflow)?;
+        }
+    }
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    let region = attestation::require_attested(
+        poll.require_attestation,
+        &poll_key,
+        attestation::AttestationAccounts {
+            voter: &ctx.accounts.voter,
+            attestation: &ctx.accounts.attestation,
+            issuer: &ctx.accounts.attestation_issuer,
+            identity_vote: &ctx.accounts.identity_vote,
+            system_program: &ctx.accounts.system_program,
+        },
+        clock.unix_timestamp,
+    )?;
+    attestation::record_regional_vote(&poll_key, &mut ctx.accounts.region_tally, region, vote_account)?;
+
+    msg!(
+        "ApprovalVote: {} approved {:#08b} on poll {}",
+        voter_key,
+        approvals,
+        poll.poll_id
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CastApprovalVote<'info> {
+    /// Voter (pays VoteAccount rent only)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// The approval poll being voted on
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// Vote record PDA — `init`, so each identity votes once
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    /// The voter's AllowlistPass (allowlist-gated polls)
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// The voter's Attestation (attestation-gated polls)
+    #[account(
+        seeds = [b"attestation", voter.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Whitelist entry of the attestation's issuer
+    pub attestation_issuer: Option<Account<'info, AttestationIssuer>>,
+
+    /// CHECK: IdentityVote PDA ["identity_vote", poll, identity_hash] —
+    /// verified and created by `attestation::require_attested`
+    #[account(mut)]
+    pub identity_vote: Option<UncheckedAccount<'info>>,
+
+    /// RegionTally of the attestation's region (attestation-gated polls)
+    #[account(mut)]
+    pub region_tally: Option<Account<'info, RegionTally>>,
+
+    pub system_program: Program<'info, System>,
+}
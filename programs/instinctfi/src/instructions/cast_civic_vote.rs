@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistPass, Attestation, AttestationIssuer, PollAccount, RegionTally, VoteAccount};
+use crate::errors::InstinctFiError;
+use crate::{allowlist, attestation};
+use super::cast_vote::init_vote_record;
+
+/// Cast the voter's single, free vote on a civic poll.
+///
+/// The voter must present an AllowlistPass for the poll's current root
+/// (see `verify_allowlist`) and/or, on polls with `require_attestation`,
+/// their issuer Attestation. The VoteAccount is created here with `init`,
+/// so a second vote by the same wallet fails; attestation-gated polls also
+/// claim the identity's IdentityVote, so other wallets of the same person
+/// fail too, and add the vote to the attestation region's RegionTally.
+/// No funds move.
+pub fn handler(ctx: Context<CastCivicVote>, _poll_id: u64, option_index: u8) -> Result<()> {
+    let clock = Clock::get()?;
+    let voter_key = ctx.accounts.voter.key();
+    let poll_key = ctx.accounts.poll_account.key();
+
+    // ── Guards ──
+    let poll = &ctx.accounts.poll_account;
+    require!(poll.poll_kind == PollAccount::KIND_CIVIC, InstinctFiError::NotACivicPoll);
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+    require!(poll.has_started(&clock), InstinctFiError::PollNotStarted);
+    require!(!poll.is_ended(&clock), InstinctFiError::PollAlreadyEnded);
+    require!(
+        (option_index as usize) < poll.options.len(),
+        InstinctFiError::InvalidOption
+    );
+    require!(
+        poll.allowlist_root != allowlist::OPEN_POLL_ROOT || poll.require_attestation,
+        InstinctFiError::CivicPollNotGated
+    );
+
+    // ── Record exactly one vote ──
+    let poll = &mut ctx.accounts.poll_account;
+    let vote_account = &mut ctx.accounts.vote_account;
+    init_vote_record(poll, vote_account, poll_key, voter_key, ctx.bumps.vote_account)?;
+    vote_account.votes_per_option[option_index as usize] = 1;
+    poll.vote_counts[option_index as usize] = poll.vote_counts[option_index as usize]
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    let region = attestation::require_attested(
+        poll.require_attestation,
+        &poll_key,
+        attestation::AttestationAccounts {
+            voter: &ctx.accounts.voter,
+            attestation: &ctx.accounts.attestation,
+            issuer: &ctx.accounts.attestation_issuer,
+            identity_vote: &ctx.accounts.identity_vote,
+            system_program: &ctx.accounts.system_program,
+        },
+        clock.unix_timestamp,
+    )?;
+    attestation::record_regional_vote(&poll_key, &mut ctx.accounts.region_tally, region, vote_account)?;
+
+    msg!(
+        "CivicVote: {} voted option {} on poll {}",
+        voter_key,
+        option_index,
+        poll.poll_id
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CastCivicVote<'info> {
+    /// Voter (pays VoteAccount rent only)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// The civic poll being voted on
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// Vote record PDA — `init`, so each identity votes once
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    /// The voter's AllowlistPass (allowlist-gated polls)
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// The voter's Attestation (attestation-gated polls)
+    #[account(
+        seeds = [b"attestation", voter.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Whitelist entry of the attestation's issuer
+    pub attestation_issuer: Option<Account<'info, AttestationIssuer>>,
+
+    /// CHECK: IdentityVote PDA ["identity_vote", poll, identity_hash] —
+    /// verified and created by `attestation::require_attested`
+    #[account(mut)]
+    pub identity_vote: Option<UncheckedAccount<'info>>,
+
+    /// RegionTally of the attestation's region (attestation-gated polls)
+    #[account(mut)]
+    pub region_tally: Option<Account<'info, RegionTally>>,
+
+    pub system_program: Program<'info, System>,
+}
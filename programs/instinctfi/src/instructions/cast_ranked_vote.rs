@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistPass, Attestation, AttestationIssuer, PollAccount, RegionTally, VoteAccount};
+use crate::errors::InstinctFiError;
+use crate::{allowlist, attestation};
+use super::cast_vote::init_vote_record;
+
+/// Cast the voter's single, free ranked ballot on a ranked-choice poll.
+///
+/// `ranking` lists distinct option indices, most preferred first; it may
+/// rank fewer than all options. The ballot is stored on the VoteAccount
+/// (created with `init`, so each identity votes once) and its first choice
+/// is added to `vote_counts` as the first-preference tally. The winner is
+/// decided later by `tally_ranked`. Eligibility is checked as in
+/// `cast_civic_vote` (AllowlistPass and/or Attestation).
+pub fn handler(ctx: Context<CastRankedVote>, _poll_id: u64, ranking: Vec<u8>) -> Result<()> {
+    let clock = Clock::get()?;
+    let voter_key = ctx.accounts.voter.key();
+    let poll_key = ctx.accounts.poll_account.key();
+
+    // ── Guards ──
+    let poll = &ctx.accounts.poll_account;
+    require!(poll.is_ranked(), InstinctFiError::NotARankedPoll);
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+    require!(poll.has_started(&clock), InstinctFiError::PollNotStarted);
+    require!(!poll.is_ended(&clock), InstinctFiError::PollAlreadyEnded);
+    require!(
+        poll.allowlist_root != allowlist::OPEN_POLL_ROOT || poll.require_attestation,
+        InstinctFiError::CivicPollNotGated
+    );
+
+    // ── Validate ranking: non-empty, valid, distinct ──
+    require!(
+        !ranking.is_empty() && ranking.len() <= poll.options.len(),
+        InstinctFiError::InvalidRanking
+    );
+    let mut seen = [false; 6];
+    for &option in &ranking {
+        let idx = option as usize;
+        require!(idx < poll.options.len() && !seen[idx], InstinctFiError::InvalidRanking);
+        seen[idx] = true;
+    }
+
+    // ── Record the ballot ──
+    let poll = &mut ctx.accounts.poll_account;
+    let vote_account = &mut ctx.accounts.vote_account;
+    init_vote_record(poll, vote_account, poll_key, voter_key, ctx.bumps.vote_account)?;
+    let first = ranking[0] as usize;
+    vote_account.votes_per_option[first] = 1;
+    vote_account.ranking = ranking;
+    poll.vote_counts[first] = poll.vote_counts[first]
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    let region = attestation::require_attested(
+        poll.require_attestation,
+        &poll_key,
+        attestation::AttestationAccounts {
+            voter: &ctx.accounts.voter,
+            attestation: &ctx.accounts.attestation,
+            issuer: &ctx.accounts.attestation_issuer,
+            identity_vote: &ctx.accounts.identity_vote,
+            system_program: &ctx.accounts.system_program,
+        },
+        clock.unix_timestamp,
+    )?;
+    attestation::record_regional_vote(&poll_key, &mut ctx.accounts.region_tally, region, vote_account)?;
+
+    msg!(
+        "RankedVote: {} ranked {:?} on poll {}",
+        voter_key,
+        vote_account.ranking,
+        poll.poll_id
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CastRankedVote<'info> {
+    /// Voter (pays VoteAccount rent only)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// The ranked-choice poll being voted on
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// Vote record PDA holding the ballot — `init`, so each identity votes once
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    /// The voter's AllowlistPass (allowlist-gated polls)
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// The voter's Attestation (attestation-gated polls)
+    #[account(
+        seeds = [b"attestation", voter.key().as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Option<Account<'info, Attestation>>,
+
+    /// Whitelist entry of the attestation's issuer
+    pub attestation_issuer: Option<Account<'info, AttestationIssuer>>,
+
+    /// CHECK: IdentityVote PDA ["identity_vote", poll, identity_hash] —
+    /// verified and created by `attestation::require_attested`
+    #[account(mut)]
+    pub identity_vote: Option<UncheckedAccount<'info>>,
+
+    /// RegionTally of the attestation's region (attestation-gated polls)
+    #[account(mut)]
+    pub region_tally: Option<Account<'info, RegionTally>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,79 +1,158 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{PollAccount, VoteAccount, UserAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AllowlistPass, PollAccount, VoteAccount, UserAccount};
 use crate::errors::InstinctFiError;
+use crate::vault;
+use crate::pricing;
+use crate::allowlist;
+use crate::token_gate;
+use crate::receipt;
+use crate::events::EndTimeExtended;
 
 /// Buy `num_coins` option-coins for `option_index` on a poll.
-/// Cost = num_coins × unit_price (in lamports).
+/// Cost follows the option's bonding curve (flat: num_coins × unit_price;
+/// quadratic: the marginal cost on top of the voter's existing coins on the
+/// option), in lamports. The vote is rejected if the cost exceeds `max_total_cost`
+/// — quote it off-chain with `pricing::quote_cast_vote`.
 /// Real SOL is transferred from the voter to the treasury PDA.
+///
+/// On the voter's first vote on the poll, passing the optional receipt
+/// accounts also mints a non-transferable Token-2022 participation receipt
+/// (see `receipt::mint_receipt`). They are ignored on later votes.
 pub fn handler(
     ctx: Context<CastVote>,
     _poll_id: u64,
     option_index: u8,
     num_coins: u64,
+    max_total_cost: u64,
 ) -> Result<()> {
     let clock = Clock::get()?;
-
-    // Read poll data immutably first
-    let poll_status = ctx.accounts.poll_account.status;
-    let poll_end_time = ctx.accounts.poll_account.end_time;
-    let poll_options_len = ctx.accounts.poll_account.options.len();
-    let poll_unit_price = ctx.accounts.poll_account.unit_price;
-    let poll_creator = ctx.accounts.poll_account.creator;
+    let voter_key = ctx.accounts.voter.key();
 
     // ── Guards ──
-    require!(poll_status == PollAccount::STATUS_ACTIVE, InstinctFiError::PollNotActive);
-    require!(clock.unix_timestamp < poll_end_time, InstinctFiError::PollAlreadyEnded);
-    require!((option_index as usize) < poll_options_len, InstinctFiError::InvalidOption);
-    require!(num_coins > 0, InstinctFiError::ZeroCoins);
+    require_open_for_votes(&ctx.accounts.poll_account, &voter_key, &clock)?;
     require!(
-        ctx.accounts.voter.key() != poll_creator,
-        InstinctFiError::CreatorCannotVote
+        (option_index as usize) < ctx.accounts.poll_account.options.len(),
+        InstinctFiError::InvalidOption
     );
+    require!(num_coins > 0, InstinctFiError::ZeroCoins);
 
-    // ── Calculate cost in lamports ──
-    let cost = num_coins
-        .checked_mul(poll_unit_price)
-        .ok_or(InstinctFiError::Overflow)?;
+    // ── Calculate cost in lamports (integrates the option's bonding curve) ──
+    // Same computation clients use via `pricing::quote_cast_vote`; checked
+    // against the signed `max_total_cost` before any SOL moves.
+    let held = pricing::held_coins(&ctx.accounts.vote_account, option_index);
+    let cost = pricing::quote_cast_vote(&ctx.accounts.poll_account, option_index, num_coins, held)?
+        .total_cost;
+    require!(cost <= max_total_cost, InstinctFiError::SlippageExceeded);
 
-    // ── Transfer real SOL from voter → treasury PDA ──
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.voter.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        ),
+    // ── Transfer real SOL (or SPL collateral) from voter → treasury PDA ──
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    vault::deposit(
+        ctx.accounts.voter.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
         cost,
     )?;
 
-    // ── Update poll vote counts & pool ──
+    // ── Update poll, VoteAccount & user stats ──
     let poll_key = ctx.accounts.poll_account.key();
     let poll = &mut ctx.accounts.poll_account;
-    poll.vote_counts[option_index as usize] = poll.vote_counts[option_index as usize]
-        .checked_add(num_coins)
-        .ok_or(InstinctFiError::Overflow)?;
-    poll.total_pool = poll.total_pool
-        .checked_add(cost)
-        .ok_or(InstinctFiError::Overflow)?;
-
-    // ── Update or init VoteAccount ──
     let vote_account = &mut ctx.accounts.vote_account;
+    let first_vote = vote_account.voter == Pubkey::default();
+    init_vote_record(poll, vote_account, poll_key, voter_key, ctx.bumps.vote_account)?;
+    record_coins(poll, vote_account, option_index as usize, num_coins, cost)?;
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    token_gate::require_token_gate(poll, &ctx.accounts.gate_token_account, &voter_key)?;
+    apply_soft_close(poll, poll_key, voter_key, cost, &clock)?;
+    record_user_stake(&mut ctx.accounts.user_account, num_coins, cost)?;
+
+    // ── Optional participation receipt on the first vote ──
+    let receipt_accounts = receipt::receipt_accounts(
+        &ctx.accounts.voter,
+        &ctx.accounts.treasury,
+        &ctx.accounts.system_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_token_program,
+        &ctx.accounts.associated_token_program,
+    )?;
+    if let (true, Some(accounts), Some(mint_bump)) = (first_vote, receipt_accounts, ctx.bumps.receipt_mint) {
+        receipt::mint_receipt(&accounts, &ctx.accounts.poll_account, &poll_key, option_index, mint_bump)?;
+    }
+
+    msg!(
+        "Vote: {} coins on option {} for poll {}, cost={} lamports",
+        num_coins,
+        option_index,
+        ctx.accounts.poll_account.poll_id,
+        cost
+    );
+    Ok(())
+}
+
+/// Guards shared by every instruction that buys option-coins.
+pub(crate) fn require_open_for_votes(poll: &PollAccount, voter: &Pubkey, clock: &Clock) -> Result<()> {
+    require!(poll.status == PollAccount::STATUS_ACTIVE, InstinctFiError::PollNotActive);
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    require!(poll.has_started(clock), InstinctFiError::PollNotStarted);
+    require!(clock.unix_timestamp < poll.end_time, InstinctFiError::PollAlreadyEnded);
+    require!(*voter != poll.creator, InstinctFiError::CreatorCannotVote);
+    Ok(())
+}
+
+/// Initializes a fresh VoteAccount on the voter's first vote and counts
+/// the voter on the poll. No-op for an existing record.
+pub(crate) fn init_vote_record(
+    poll: &mut PollAccount,
+    vote_account: &mut VoteAccount,
+    poll_key: Pubkey,
+    voter: Pubkey,
+    bump: u8,
+) -> Result<()> {
     if vote_account.voter == Pubkey::default() {
         // First vote by this user on this poll
         vote_account.poll = poll_key;
-        vote_account.voter = ctx.accounts.voter.key();
-        vote_account.votes_per_option = vec![0u64; poll_options_len];
+        vote_account.voter = voter;
+        vote_account.votes_per_option = vec![0u64; poll.options.len()];
         vote_account.total_staked = 0;
         vote_account.claimed = false;
-        vote_account.bump = ctx.bumps.vote_account;
+        vote_account.bump = bump;
+        vote_account.ranking = Vec::new();
+        vote_account.tallied_round = 0;
         poll.total_voters = poll.total_voters
             .checked_add(1)
             .ok_or(InstinctFiError::Overflow)?;
     }
-    vote_account.votes_per_option[option_index as usize] = vote_account.votes_per_option
-        [option_index as usize]
+    Ok(())
+}
+
+/// Credits `num_coins` bought for `cost` lamports on `option_index` to the
+/// poll's tallies and pool and to the voter's record, enforcing the poll's
+/// per-voter stake caps.
+pub(crate) fn record_coins(
+    poll: &mut PollAccount,
+    vote_account: &mut VoteAccount,
+    option_index: usize,
+    num_coins: u64,
+    cost: u64,
+) -> Result<()> {
+    poll.vote_counts[option_index] = poll.vote_counts[option_index]
+        .checked_add(num_coins)
+        .ok_or(InstinctFiError::Overflow)?;
+    poll.total_pool = poll.total_pool
+        .checked_add(cost)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    vote_account.votes_per_option[option_index] = vote_account.votes_per_option[option_index]
         .checked_add(num_coins)
         .ok_or(InstinctFiError::Overflow)?;
     vote_account.total_staked = vote_account
@@ -81,22 +160,110 @@ pub fn handler(
         .checked_add(cost)
         .ok_or(InstinctFiError::Overflow)?;
 
-    // ── Update user stats ──
-    let user = &mut ctx.accounts.user_account;
+    // ── Anti-whale caps (0 = no cap) ──
+    require!(
+        poll.max_stake_per_voter == 0 || vote_account.total_staked <= poll.max_stake_per_voter,
+        InstinctFiError::VoterStakeCapExceeded
+    );
+    require!(
+        poll.max_coins_per_option == 0
+            || vote_account.votes_per_option[option_index] <= poll.max_coins_per_option,
+        InstinctFiError::OptionCoinCapExceeded
+    );
+    Ok(())
+}
+
+/// Soft close (anti-sniping), called by every vote path once the purchase
+/// is recorded, with `stake` = the call's total cost. Stake landing within
+/// the final `soft_close_window` seconds accumulates on the poll; once it
+/// reaches `soft_close_threshold_bps` of the pool as it stood when the window
+/// opened, `end_time` moves forward by `soft_close_extension` seconds (at most
+/// `soft_close_max_extensions` times) and the count starts over. Splitting a
+/// late purchase across calls or options therefore changes nothing. Each
+/// extension is counted on the poll and emitted as `EndTimeExtended`.
+pub(crate) fn apply_soft_close(
+    poll: &mut PollAccount,
+    poll_key: Pubkey,
+    voter: Pubkey,
+    stake: u64,
+    clock: &Clock,
+) -> Result<()> {
+    if !poll.has_soft_close() || poll.soft_close_extensions >= poll.soft_close_max_extensions {
+        return Ok(());
+    }
+    let remaining = poll.end_time.saturating_sub(clock.unix_timestamp);
+    if remaining > poll.soft_close_window as i64 {
+        return Ok(());
+    }
+    let pool_before = poll.total_pool.saturating_sub(stake);
+    let triggered = accrue_window_stake(
+        &mut poll.soft_close_window_stake,
+        &mut poll.soft_close_window_base,
+        pool_before,
+        stake,
+        poll.soft_close_threshold_bps,
+    )?;
+    if !triggered {
+        return Ok(());
+    }
+    let window_stake = poll.soft_close_window_stake;
+    let window_base = poll.soft_close_window_base;
+    poll.soft_close_window_stake = 0;
+    poll.soft_close_window_base = 0;
+
+    let previous_end_time = poll.end_time;
+    poll.end_time = poll.end_time
+        .checked_add(poll.soft_close_extension as i64)
+        .ok_or(InstinctFiError::Overflow)?;
+    poll.soft_close_extensions += 1;
+
+    emit!(EndTimeExtended {
+        poll: poll_key,
+        poll_id: poll.poll_id,
+        voter,
+        window_stake,
+        window_base,
+        previous_end_time,
+        new_end_time: poll.end_time,
+        extension: poll.soft_close_extensions,
+    });
+    msg!(
+        "SoftClose: poll {} extended to {} ({}/{})",
+        poll.poll_id,
+        poll.end_time,
+        poll.soft_close_extensions,
+        poll.soft_close_max_extensions
+    );
+    Ok(())
+}
+
+/// Adds an in-window purchase of `stake` to the window total, opening the
+/// window at `pool_before` on its first vote. Returns whether the total has
+/// reached `threshold_bps` of the pool at the window's opening.
+pub(crate) fn accrue_window_stake(
+    window_stake: &mut u64,
+    window_base: &mut u64,
+    pool_before: u64,
+    stake: u64,
+    threshold_bps: u16,
+) -> Result<bool> {
+    if *window_stake == 0 {
+        *window_base = pool_before;
+    }
+    *window_stake = window_stake
+        .checked_add(stake)
+        .ok_or(InstinctFiError::Overflow)?;
+    Ok((*window_stake as u128) * 10_000 >= (*window_base as u128) * (threshold_bps as u128))
+}
+
+/// Adds a purchase to the voter's lifetime stats.
+pub(crate) fn record_user_stake(user: &mut UserAccount, num_coins: u64, cost: u64) -> Result<()> {
     user.total_votes_cast = user.total_votes_cast
         .checked_add(num_coins)
         .ok_or(InstinctFiError::Overflow)?;
     user.total_staked = user.total_staked
         .checked_add(cost)
         .ok_or(InstinctFiError::Overflow)?;
-
-    msg!(
-        "Vote: {} coins on option {} for poll {}, cost={} lamports",
-        num_coins,
-        option_index,
-        ctx.accounts.poll_account.poll_id,
-        cost
-    );
     Ok(())
 }
 
@@ -144,4 +311,103 @@ pub struct CastVote<'info> {
     pub vote_account: Account<'info, VoteAccount>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: voter's token account paying for the coins
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = voter,
+        token::token_program = token_program,
+    )]
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Optional receipt mint PDA — created as a Token-2022 mint on the first vote
+    #[account(
+        mut,
+        seeds = [b"receipt", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub receipt_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional voter's receipt ATA — address verified by the associated token program
+    #[account(mut)]
+    pub receipt_token_account: Option<UncheckedAccount<'info>>,
+
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// Allowlisted polls only: the voter's AllowlistPass
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// Token-gated polls only: voter's token account for the poll's gate mint
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOL: u64 = 1_000_000_000;
+
+    #[test]
+    fn window_stake_accumulates_across_small_votes() {
+        let (mut stake, mut base) = (0, 0);
+        // 10% of a 100 SOL pool, bought in 4 SOL slices
+        assert!(!accrue_window_stake(&mut stake, &mut base, 100 * SOL, 4 * SOL, 1_000).unwrap());
+        assert!(!accrue_window_stake(&mut stake, &mut base, 104 * SOL, 4 * SOL, 1_000).unwrap());
+        assert!(accrue_window_stake(&mut stake, &mut base, 108 * SOL, 4 * SOL, 1_000).unwrap());
+        assert_eq!(stake, 12 * SOL);
+        // The base stays at the pool before the window's first vote
+        assert_eq!(base, 100 * SOL);
+    }
+
+    #[test]
+    fn window_stake_threshold_is_inclusive() {
+        let (mut stake, mut base) = (0, 0);
+        assert!(accrue_window_stake(&mut stake, &mut base, 100 * SOL, 10 * SOL, 1_000).unwrap());
+        let (mut stake, mut base) = (0, 0);
+        assert!(!accrue_window_stake(&mut stake, &mut base, 100 * SOL, 10 * SOL - 1, 1_000).unwrap());
+    }
+
+    #[test]
+    fn window_stake_is_split_invariant() {
+        // One 9 SOL purchase and three 3 SOL purchases trigger alike
+        let (mut whole, mut whole_base) = (0, 0);
+        let whole_hit = accrue_window_stake(&mut whole, &mut whole_base, 60 * SOL, 9 * SOL, 1_500).unwrap();
+
+        let (mut split, mut split_base) = (0, 0);
+        let mut pool = 60 * SOL;
+        let mut split_hit = false;
+        for _ in 0..3 {
+            split_hit = accrue_window_stake(&mut split, &mut split_base, pool, 3 * SOL, 1_500).unwrap();
+            pool += 3 * SOL;
+        }
+        assert!(whole_hit && split_hit);
+        assert_eq!((whole, whole_base), (split, split_base));
+    }
+
+    #[test]
+    fn first_vote_into_empty_pool_triggers() {
+        let (mut stake, mut base) = (0, 0);
+        assert!(accrue_window_stake(&mut stake, &mut base, 0, 1, 10_000).unwrap());
+    }
 }
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{AllowlistPass, PollAccount, VoteAccount, UserAccount};
+use crate::errors::InstinctFiError;
+use crate::vault;
+use crate::pricing;
+use crate::allowlist;
+use crate::token_gate;
+use super::cast_vote::{apply_soft_close, init_vote_record, record_coins, record_user_stake, require_open_for_votes};
+
+/// One leg of a batch vote: buy `num_coins` on `option_index`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct VoteEntry {
+    pub option_index: u8,
+    pub num_coins: u64,
+}
+
+/// Buy coins on several options of one poll in a single instruction.
+///
+/// Every entry is validated (valid, distinct option; at least one coin) and
+/// priced in order against the option's bonding curve, then the combined cost
+/// is checked against `max_total_cost` and moved in ONE transfer. Tallies
+/// and the VoteAccount are updated for all entries or — on any error — none.
+pub fn handler(
+    ctx: Context<CastVotes>,
+    _poll_id: u64,
+    entries: Vec<VoteEntry>,
+    max_total_cost: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let voter_key = ctx.accounts.voter.key();
+    let poll_key = ctx.accounts.poll_account.key();
+    let num_options = ctx.accounts.poll_account.options.len();
+
+    // ── Guards ──
+    require_open_for_votes(&ctx.accounts.poll_account, &voter_key, &clock)?;
+    require!(
+        !entries.is_empty() && entries.len() <= num_options,
+        InstinctFiError::InvalidOption
+    );
+
+    let poll = &mut ctx.accounts.poll_account;
+    let vote_account = &mut ctx.accounts.vote_account;
+    init_vote_record(poll, vote_account, poll_key, voter_key, ctx.bumps.vote_account)?;
+
+    // ── Validate, price and record every entry ──
+    let mut seen = [false; 6];
+    let mut total_coins: u64 = 0;
+    let mut total_cost: u64 = 0;
+    for entry in &entries {
+        let idx = entry.option_index as usize;
+        require!(idx < num_options && !seen[idx], InstinctFiError::InvalidOption);
+        seen[idx] = true;
+
+        let held = pricing::held_coins(vote_account, entry.option_index);
+        let cost = pricing::quote_cast_vote(poll, entry.option_index, entry.num_coins, held)?.total_cost;
+        record_coins(poll, vote_account, idx, entry.num_coins, cost)?;
+
+        total_coins = total_coins
+            .checked_add(entry.num_coins)
+            .ok_or(InstinctFiError::Overflow)?;
+        total_cost = total_cost
+            .checked_add(cost)
+            .ok_or(InstinctFiError::Overflow)?;
+    }
+    require!(total_cost <= max_total_cost, InstinctFiError::SlippageExceeded);
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    token_gate::require_token_gate(poll, &ctx.accounts.gate_token_account, &voter_key)?;
+    apply_soft_close(poll, poll_key, voter_key, total_cost, &clock)?;
+
+    // ── One combined transfer: voter → treasury PDA ──
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    vault::deposit(
+        ctx.accounts.voter.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
+        total_cost,
+    )?;
+
+    record_user_stake(&mut ctx.accounts.user_account, total_coins, total_cost)?;
+
+    msg!(
+        "Votes: {} coins across {} options for poll {}, cost={} lamports",
+        total_coins,
+        entries.len(),
+        ctx.accounts.poll_account.poll_id,
+        total_cost
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CastVotes<'info> {
+    /// Voter (pays SOL)
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// Voter's user profile
+    #[account(
+        mut,
+        seeds = [b"user", voter.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// The poll being voted on
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA for this poll — receives SOL
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Vote record PDA: tracks this voter's coins in this poll
+    #[account(
+        init_if_needed,
+        payer = voter,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: voter's token account paying for the coins
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = voter,
+        token::token_program = token_program,
+    )]
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Allowlisted polls only: the voter's AllowlistPass
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// Token-gated polls only: voter's token account for the poll's gate mint
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
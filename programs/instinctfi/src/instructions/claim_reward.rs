@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{PollAccount, VoteAccount, UserAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{OrderBook, PollAccount, VoteAccount, UserAccount};
 use crate::errors::InstinctFiError;
+use crate::vault;
 
 /// Claim winnings for a settled poll.
 ///
@@ -18,69 +19,41 @@ pub fn handler(ctx: Context<ClaimReward>, _poll_id: u64) -> Result<()> {
     // ── Read data before any mutable borrows ──
     let poll_key = ctx.accounts.poll_account.key();
     let treasury_bump = ctx.accounts.poll_account.treasury_bump;
-    let status = ctx.accounts.poll_account.status;
-    let winning_option = ctx.accounts.poll_account.winning_option;
-    let total_pool = ctx.accounts.poll_account.total_pool;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
     let poll_id_val = ctx.accounts.poll_account.poll_id;
 
-    // ── Guards ──
-    require!(status == PollAccount::STATUS_SETTLED, InstinctFiError::NotSettled);
-    require!(winning_option != 255, InstinctFiError::NoVotes);
-
-    let winning_idx = winning_option as usize;
-    let total_winning_votes = ctx.accounts.poll_account.vote_counts[winning_idx];
-
-    let vote_claimed = ctx.accounts.vote_account.claimed;
-    require!(!vote_claimed, InstinctFiError::AlreadyClaimed);
-
-    let user_winning_votes = ctx.accounts.vote_account.votes_per_option[winning_idx];
-    require!(user_winning_votes > 0, InstinctFiError::NotAWinner);
-
-    // ── Calculate reward (u128 to avoid overflow) ──
-    let reward = (user_winning_votes as u128)
-        .checked_mul(total_pool as u128)
-        .ok_or(InstinctFiError::Overflow)?
-        .checked_div(total_winning_votes as u128)
-        .ok_or(InstinctFiError::Overflow)? as u64;
-
-    // ── Ensure treasury has enough SOL (preserve rent-exempt minimum) ──
-    let rent = Rent::get()?;
-    let rent_exempt_min = rent.minimum_balance(0); // treasury has no data, just SOL
-    let available = ctx.accounts.treasury.lamports()
-        .saturating_sub(rent_exempt_min);
+    // ── Guards & reward ──
+    let (reward, user_winning_votes, total_winning_votes) =
+        winning_reward(&ctx.accounts.poll_account, &ctx.accounts.vote_account)?;
+    OrderBook::require_no_orders(&ctx.accounts.order_book, Some(&ctx.accounts.claimer.key()))?;
+
+    // ── Ensure treasury has enough (SOL: preserve rent-exempt minimum) ──
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.claimer_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    let available = vault::available(&ctx.accounts.treasury, token.as_ref(), set_collateral)?;
     require!(
         available >= reward,
         InstinctFiError::TreasuryInsufficient
     );
 
-    // ── Transfer real SOL from treasury → claimer ──
-    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
-    let signer_seeds = &[seeds];
-
-    system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.treasury.to_account_info(),
-                to: ctx.accounts.claimer.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    // ── Transfer real SOL (or SPL collateral) from treasury → claimer ──
+    vault::withdraw(
+        &poll_key,
+        treasury_bump,
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.claimer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
         reward,
     )?;
 
-    // ── Mark claimed ──
-    let vote = &mut ctx.accounts.vote_account;
-    vote.claimed = true;
-
-    // ── Update user stats ──
-    let user = &mut ctx.accounts.user_account;
-    user.total_winnings = user.total_winnings
-        .checked_add(reward)
-        .ok_or(InstinctFiError::Overflow)?;
-    user.polls_won = user.polls_won
-        .checked_add(1)
-        .ok_or(InstinctFiError::Overflow)?;
+    // ── Mark claimed & update user stats ──
+    record_claim(&mut ctx.accounts.vote_account, &mut ctx.accounts.user_account, reward)?;
 
     msg!(
         "Claim: poll={} user={} votes={}/{} reward={} lamports",
@@ -93,6 +66,40 @@ pub fn handler(ctx: Context<ClaimReward>, _poll_id: u64) -> Result<()> {
     Ok(())
 }
 
+/// Guards a claim on a settled poll and computes the voter's reward
+/// (u128 to avoid overflow). Returns
+/// `(reward, user_winning_votes, total_winning_votes)`.
+pub(crate) fn winning_reward(poll: &PollAccount, vote: &VoteAccount) -> Result<(u64, u64, u64)> {
+    require!(poll.status == PollAccount::STATUS_SETTLED, InstinctFiError::NotSettled);
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    require!(poll.winning_option != 255, InstinctFiError::NoVotes);
+    require!(!vote.claimed, InstinctFiError::AlreadyClaimed);
+
+    let winning_idx = poll.winning_option as usize;
+    let total_winning_votes = poll.vote_counts[winning_idx];
+    let user_winning_votes = vote.votes_per_option[winning_idx];
+    require!(user_winning_votes > 0, InstinctFiError::NotAWinner);
+
+    let reward = (user_winning_votes as u128)
+        .checked_mul(poll.total_pool as u128)
+        .ok_or(InstinctFiError::Overflow)?
+        .checked_div(total_winning_votes as u128)
+        .ok_or(InstinctFiError::Overflow)? as u64;
+    Ok((reward, user_winning_votes, total_winning_votes))
+}
+
+/// Marks the vote record claimed and credits the winner's lifetime stats.
+pub(crate) fn record_claim(vote: &mut VoteAccount, user: &mut UserAccount, reward: u64) -> Result<()> {
+    vote.claimed = true;
+    user.total_winnings = user.total_winnings
+        .checked_add(reward)
+        .ok_or(InstinctFiError::Overflow)?;
+    user.polls_won = user.polls_won
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+    Ok(())
+}
+
 // ─── Accounts ────────────────────────────────────────────────────────────────
 
 #[derive(Accounts)]
@@ -134,4 +141,33 @@ pub struct ClaimReward<'info> {
     pub vote_account: Account<'info, VoteAccount>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: The poll's order book PDA (may not exist) — claims wait until
+    /// the voter's orders are collected with `cancel_order`
+    #[account(seeds = [b"order_book", poll_account.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: claimer's token account receiving the reward
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = claimer,
+        token::token_program = token_program,
+    )]
+    pub claimer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, PriceCurve, UserAccount};
+use crate::errors::InstinctFiError;
+use crate::allowlist;
+
+/// Everything `create_civic_poll` sets besides the poll id.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CivicPollParams {
+    pub title: String,
+    pub description: String,
+    pub category: String,
+    pub image_url: String,
+    pub options: Vec<String>,
+    pub end_time: i64,
+    /// Merkle root of eligible identities (`OPEN_POLL_ROOT` = no allowlist)
+    pub allowlist_root: [u8; 32],
+    /// `KIND_CIVIC`, `KIND_RANKED` or `KIND_APPROVAL`
+    pub poll_kind: u8,
+    pub require_attestation: bool,
+}
+
+/// Creates a free one-person-one-vote civic poll (Janamat opinion mode).
+///
+/// No investment, fees or treasury funds: each eligible identity casts
+/// exactly one ballot, and the result is the final tally, recorded by
+/// `finalize_civic_poll`. `poll_kind` picks the ballot:
+///
+/// - `KIND_CIVIC`: one option per identity (`cast_civic_vote`)
+/// - `KIND_RANKED`: a ranking (`cast_ranked_vote`), decided by instant
+///   runoff (`tally_ranked`)
+/// - `KIND_APPROVAL`: any subset of options (`cast_approval_vote`)
+///
+/// Eligibility is the merkle allowlist `allowlist_root` (one leaf per
+/// identity), issuer attestations (`require_attestation`: one vote per
+/// attested identity, whatever wallet it uses), or both. At least one gate
+/// is required; the creator may replace the root before the first vote but
+/// only clear it if attestations are required.
+///
+/// Civic polls reuse PollAccount / VoteAccount; every market instruction
+/// (coins, settlement, claims, sponsorship, outcome tokens) rejects them.
+pub fn handler(
+    ctx: Context<CreateCivicPoll>,
+    poll_id: u64,
+    params: CivicPollParams,
+) -> Result<()> {
+    let CivicPollParams {
+        title,
+        description,
+        category,
+        image_url,
+        options,
+        end_time,
+        allowlist_root,
+        poll_kind,
+        require_attestation,
+    } = params;
+
+    // ── Validate inputs ──
+    require!(title.len() <= 64, InstinctFiError::TitleTooLong);
+    require!(description.len() <= 256, InstinctFiError::DescriptionTooLong);
+    require!(category.len() <= 32, InstinctFiError::CategoryTooLong);
+    require!(image_url.len() <= 256, InstinctFiError::ImageUrlTooLong);
+    require!(options.len() >= 2 && options.len() <= 6, InstinctFiError::InvalidOptionCount);
+    for opt in &options {
+        require!(opt.len() <= 32, InstinctFiError::OptionLabelTooLong);
+    }
+    require!(
+        allowlist_root != allowlist::OPEN_POLL_ROOT || require_attestation,
+        InstinctFiError::CivicPollNotGated
+    );
+    require!(
+        matches!(
+            poll_kind,
+            PollAccount::KIND_CIVIC | PollAccount::KIND_RANKED | PollAccount::KIND_APPROVAL
+        ),
+        InstinctFiError::NotACivicPoll
+    );
+
+    let clock = Clock::get()?;
+    require!(end_time > clock.unix_timestamp, InstinctFiError::EndTimeInPast);
+
+    // ── Initialize poll account ──
+    let poll = &mut ctx.accounts.poll_account;
+    let num_options = options.len();
+
+    poll.poll_id = poll_id;
+    poll.creator = ctx.accounts.creator.key();
+    poll.title = title;
+    poll.description = description;
+    poll.category = category;
+    poll.image_url = image_url;
+    poll.options = options;
+    poll.vote_counts = vec![0u64; num_options];
+    poll.unit_price = 0;
+    poll.end_time = end_time;
+    poll.total_pool = 0;
+    poll.creator_investment = 0;
+    poll.platform_fee = 0;
+    poll.creator_reward = 0;
+    poll.status = PollAccount::STATUS_ACTIVE;
+    poll.winning_option = 255;
+    poll.treasury_bump = ctx.bumps.treasury;
+    poll.bump = ctx.bumps.poll_account;
+    poll.total_voters = 0;
+    poll.created_at = clock.unix_timestamp;
+    poll.start_time = clock.unix_timestamp;
+    poll.soft_close_window = 0;
+    poll.soft_close_threshold_bps = 0;
+    poll.soft_close_extension = 0;
+    poll.soft_close_max_extensions = 0;
+    poll.soft_close_extensions = 0;
+    poll.soft_close_window_stake = 0;
+    poll.soft_close_window_base = 0;
+    poll.voided_refunds = 0;
+    poll.set_collateral = 0;
+    poll.price_curves = vec![PriceCurve::default(); num_options];
+    poll.collateral_mint = Pubkey::default();
+    poll.max_stake_per_voter = 0;
+    poll.max_coins_per_option = 0;
+    poll.min_total_pool = 0;
+    poll.min_voters = 0;
+    poll.creator_seeded = 0;
+    poll.total_sponsored = 0;
+    poll.allowlist_root = allowlist_root;
+    poll.gate_mint = Pubkey::default();
+    poll.gate_min_amount = 0;
+    poll.poll_kind = poll_kind;
+    poll.require_attestation = require_attestation;
+
+    // ── Update user stats ──
+    let user = &mut ctx.accounts.user_account;
+    user.total_polls_created = user.total_polls_created
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!("Civic poll {} created with {} options (kind={})", poll_id, num_options, poll_kind);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct CreateCivicPoll<'info> {
+    /// The poll creator (pays account rent only)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Creator's user profile
+    #[account(
+        mut,
+        seeds = [b"user", creator.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Poll PDA: seeds = ["poll", creator, poll_id]
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PollAccount::INIT_SPACE,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — never funded for civic polls; only its bump is
+    /// recorded so the poll has the same shape as a market poll.
+    #[account(
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
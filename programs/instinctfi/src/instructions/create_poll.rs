@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{PollAccount, UserAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{PlatformConfig, PollAccount, PriceCurve, ReferralAccount, UserAccount};
 use crate::errors::InstinctFiError;
+use crate::vault;
+use crate::referral;
+use crate::allowlist;
 
 /// Creates a new prediction poll with a real SOL investment.
 ///
@@ -12,6 +16,14 @@ use crate::errors::InstinctFiError;
 ///
 /// The creator's SOL is transferred to the treasury PDA.
 ///
+/// SPL-denominated polls: pass `collateral_mint` (plus vault, creator token
+/// account and token programs). All amounts — `unit_price`,
+/// `creator_investment`, stakes and payouts — are then in the mint's base
+/// units and held in the treasury PDA's associated token account.
+///
+/// An optional `referrer` (with the platform config) earns a share of the
+/// platform fee — see `referral::pay_referral`.
+///
 /// TODO (#48): Add a separate `withdraw_platform_fee` instruction so admin
 /// can withdraw accumulated platform fees from the treasury PDA. Currently
 /// these fees remain permanently locked. The instruction should:
@@ -54,15 +66,29 @@ pub fn handler(
         .checked_sub(creator_reward)
         .ok_or(InstinctFiError::Overflow)?;
 
-    // ── Transfer real SOL from creator → treasury PDA ──
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.creator.to_account_info(),
-                to: ctx.accounts.treasury.to_account_info(),
-            },
-        ),
+    // ── Collateral: native SOL unless a mint is passed ──
+    let collateral_mint = match &ctx.accounts.collateral_mint {
+        Some(mint) => {
+            vault::require_supported_mint(mint)?;
+            mint.key()
+        }
+        None => Pubkey::default(),
+    };
+    ctx.accounts.poll_account.collateral_mint = collateral_mint;
+
+    // ── Transfer investment from creator → treasury PDA ──
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    vault::deposit(
+        ctx.accounts.creator.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
         creator_investment,
     )?;
 
@@ -90,6 +116,41 @@ pub fn handler(
     poll.bump = ctx.bumps.poll_account;
     poll.total_voters = 0;
     poll.created_at = clock.unix_timestamp;
+    poll.start_time = clock.unix_timestamp;
+    poll.soft_close_window = 0;
+    poll.soft_close_threshold_bps = 0;
+    poll.soft_close_extension = 0;
+    poll.soft_close_max_extensions = 0;
+    poll.soft_close_extensions = 0;
+    poll.soft_close_window_stake = 0;
+    poll.soft_close_window_base = 0;
+    poll.voided_refunds = 0;
+    poll.set_collateral = 0;
+    poll.price_curves = vec![PriceCurve::default(); num_options];
+    poll.max_stake_per_voter = 0;
+    poll.max_coins_per_option = 0;
+    poll.min_total_pool = 0;
+    poll.min_voters = 0;
+    poll.creator_seeded = 0;
+    poll.total_sponsored = 0;
+    poll.allowlist_root = allowlist::OPEN_POLL_ROOT;
+    poll.gate_mint = Pubkey::default();
+    poll.gate_min_amount = 0;
+    poll.poll_kind = PollAccount::KIND_MARKET;
+    poll.require_attestation = false;
+
+    // ── Optional referral reward (out of the platform fee) ──
+    if let Some(referrer) = ctx.accounts.referrer.as_mut() {
+        referral::pay_referral(
+            &mut ctx.accounts.poll_account,
+            referrer,
+            &ctx.accounts.platform_config,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            creator_investment,
+            platform_fee,
+        )?;
+    }
 
     // ── Update user stats ──
     let user = &mut ctx.accounts.user_account;
@@ -99,7 +160,7 @@ pub fn handler(
 
     msg!(
         "Poll {} created with {} options, pool={} lamports, treasury={}",
-        poll.poll_id,
+        poll_id,
         num_options,
         pool_seed,
         ctx.accounts.treasury.key()
@@ -145,4 +206,40 @@ pub struct CreatePoll<'info> {
     pub treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL polls only: collateral mint (omit for SOL polls)
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: creator's token account funding the investment
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// Optional: referrer's ReferralAccount — earns a share of the platform fee
+    #[account(
+        mut,
+        constraint = referrer.referrer != creator.key() @ InstinctFiError::SelfReferral,
+    )]
+    pub referrer: Option<Account<'info, ReferralAccount>>,
+
+    /// Platform config — required when `referrer` is passed
+    #[account(seeds = [b"platform_config"], bump = platform_config.bump)]
+    pub platform_config: Option<Account<'info, PlatformConfig>>,
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::SessionAccount;
+use crate::errors::InstinctFiError;
+
+/// Authorize `session_key` to vote and claim on the signer's behalf until
+/// `expires_at`, spending at most `spend_cap` lamports from the signer's
+/// SessionEscrow. `scope_poll` (default pubkey = any) and `scope_category`
+/// (empty = any) restrict which polls the session may touch.
+pub fn handler(
+    ctx: Context<CreateSession>,
+    session_key: Pubkey,
+    expires_at: i64,
+    spend_cap: u64,
+    scope_poll: Pubkey,
+    scope_category: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // ── Validation ──
+    require!(expires_at > clock.unix_timestamp, InstinctFiError::InvalidSessionExpiry);
+    require!(scope_category.len() <= 32, InstinctFiError::CategoryTooLong);
+
+    let session = &mut ctx.accounts.session_account;
+    session.authority = ctx.accounts.authority.key();
+    session.session_key = session_key;
+    session.expires_at = expires_at;
+    session.spend_cap = spend_cap;
+    session.spent = 0;
+    session.scope_poll = scope_poll;
+    session.scope_category = scope_category;
+    session.bump = ctx.bumps.session_account;
+
+    msg!(
+        "Session: {} delegated to {} until {}, cap={} lamports",
+        session.authority,
+        session_key,
+        expires_at,
+        spend_cap
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    /// Wallet granting the session (pays rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SessionAccount::INIT_SPACE,
+        seeds = [b"session", authority.key().as_ref(), session_key.as_ref()],
+        bump,
+    )]
+    pub session_account: Account<'info, SessionAccount>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{Survey, UserAccount};
+use crate::errors::InstinctFiError;
+
+/// Creates a free multi-question civic survey (Janamat).
+///
+/// Instead of one PollAccount and treasury per question, a survey groups up
+/// to `Survey::MAX_QUESTIONS` questions (`add_survey_question`) under one
+/// account with one `end_time` and one SurveyResponse per voter, who answers
+/// every question in a single `answer_survey`.
+///
+/// Surveys are free-only: answers cost nothing and nothing is paid out, so
+/// there is no shared treasury — a paid multi-question market still takes
+/// one PollAccount per question.
+///
+/// With `require_attestation` each attested identity responds once, whatever
+/// wallet it uses; otherwise each wallet responds once.
+pub fn handler(
+    ctx: Context<CreateSurvey>,
+    survey_id: u64,
+    title: String,
+    description: String,
+    end_time: i64,
+    require_attestation: bool,
+) -> Result<()> {
+    require!(title.len() <= 64, InstinctFiError::TitleTooLong);
+    require!(description.len() <= 256, InstinctFiError::DescriptionTooLong);
+
+    let clock = Clock::get()?;
+    require!(end_time > clock.unix_timestamp, InstinctFiError::EndTimeInPast);
+
+    let survey = &mut ctx.accounts.survey;
+    survey.survey_id = survey_id;
+    survey.creator = ctx.accounts.creator.key();
+    survey.title = title;
+    survey.description = description;
+    survey.end_time = end_time;
+    survey.num_questions = 0;
+    survey.total_participants = 0;
+    survey.require_attestation = require_attestation;
+    survey.created_at = clock.unix_timestamp;
+    survey.bump = ctx.bumps.survey;
+
+    let user = &mut ctx.accounts.user_account;
+    user.total_polls_created = user.total_polls_created
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!("Survey {} created (attestation required: {})", survey_id, require_attestation);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(survey_id: u64)]
+pub struct CreateSurvey<'info> {
+    /// The survey creator (pays account rent only)
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Creator's user profile
+    #[account(
+        mut,
+        seeds = [b"user", creator.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Survey PDA: seeds = ["survey", creator, survey_id]
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Survey::INIT_SPACE,
+        seeds = [b"survey", creator.key().as_ref(), &survey_id.to_le_bytes()],
+        bump,
+    )]
+    pub survey: Account<'info, Survey>,
+
+    pub system_program: Program<'info, System>,
+}
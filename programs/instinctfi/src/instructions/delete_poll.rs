@@ -1,11 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::PollAccount;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::state::{OrderBook, PollAccount, VoteAccount};
 use crate::errors::InstinctFiError;
+use crate::vault;
 
 /// Deletes a poll and refunds the creator's SOL investment from the treasury.
-/// Only the creator may call this, and only when the poll has zero votes,
-/// is still active, and has not ended.
+/// For SPL-denominated polls the tokens are refunded and the vault closed.
+/// Only the creator may call this, and only when no one else holds coins
+/// (every vote is still in the creator's `seed_odds` position), with no
+/// sponsorships (sponsors are refunded from a settled or voided poll, never
+/// from a deleted one), no resting orders, while the poll is still active and
+/// has not ended.
+///
+/// A seeded poll can therefore be deleted until a voter buys in: the seed
+/// never left the treasury, and the creator position is closed alongside the
+/// poll.
 pub fn handler(ctx: Context<DeletePoll>, _poll_id: u64) -> Result<()> {
     let clock = Clock::get()?;
 
@@ -13,10 +22,16 @@ pub fn handler(ctx: Context<DeletePoll>, _poll_id: u64) -> Result<()> {
     let creator_key = ctx.accounts.poll_account.creator;
     let status = ctx.accounts.poll_account.status;
     let end_time = ctx.accounts.poll_account.end_time;
-    let vote_counts_sum: u64 = ctx.accounts.poll_account.vote_counts.iter().sum();
+    let no_voter_coins = ctx
+        .accounts
+        .poll_account
+        .has_no_voter_coins(ctx.accounts.creator_position.as_deref());
+    let creator_seeded = ctx.accounts.poll_account.creator_seeded;
     let poll_id_val = ctx.accounts.poll_account.poll_id;
     let poll_key = ctx.accounts.poll_account.key();
     let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+    let total_sponsored = ctx.accounts.poll_account.total_sponsored;
 
     // ── Permission & safety checks ──
     require!(
@@ -25,25 +40,50 @@ pub fn handler(ctx: Context<DeletePoll>, _poll_id: u64) -> Result<()> {
     );
     require!(status == PollAccount::STATUS_ACTIVE, InstinctFiError::PollNotActive);
     require!(clock.unix_timestamp < end_time, InstinctFiError::PollAlreadyEnded);
-    require!(vote_counts_sum == 0, InstinctFiError::PollHasVotes);
+    require!(no_voter_coins, InstinctFiError::PollHasVotes);
+    require!(
+        creator_seeded == 0 || ctx.accounts.creator_position.is_some(),
+        InstinctFiError::MissingCreatorPosition
+    );
+    require!(set_collateral == 0, InstinctFiError::OutstandingOutcomeTokens);
+    require!(total_sponsored == 0, InstinctFiError::PollHasSponsors);
+    OrderBook::require_no_orders(&ctx.accounts.order_book, None)?;
 
-    // ── Refund treasury SOL to creator ──
-    let treasury_balance = ctx.accounts.treasury.lamports();
+    // ── Refund treasury SOL (or SPL collateral) to creator ──
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    let treasury_balance = vault::balance(&ctx.accounts.treasury, token.as_ref());
     if treasury_balance > 0 {
+        vault::withdraw(
+            &poll_key,
+            treasury_bump,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            token.as_ref(),
+            treasury_balance,
+        )?;
+    }
+
+    // ── SPL polls: close the emptied vault, rent back to creator ──
+    if let Some(t) = token.as_ref() {
         let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
         let signer_seeds = &[seeds];
 
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.treasury.to_account_info(),
-                    to: ctx.accounts.creator.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            treasury_balance,
-        )?;
+        token_interface::close_account(CpiContext::new_with_signer(
+            t.token_program.clone(),
+            token_interface::CloseAccount {
+                account: t.vault.clone(),
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
     }
 
     // poll_account is closed automatically via `close = creator` constraint
@@ -81,4 +121,43 @@ pub struct DeletePoll<'info> {
     pub treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: The poll's order book PDA (may not exist) — must hold no
+    /// resting bids
+    #[account(seeds = [b"order_book", poll_account.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: creator's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Seeded polls only: the creator position from `seed_odds`, closed
+    /// alongside the poll
+    #[account(
+        mut,
+        seeds = [b"vote", poll_account.key().as_ref(), creator.key().as_ref()],
+        bump = creator_position.bump,
+        close = creator,
+    )]
+    pub creator_position: Option<Account<'info, VoteAccount>>,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::SessionEscrow;
+use crate::errors::InstinctFiError;
+
+/// Top up the signer's SessionEscrow with `amount` lamports, creating the
+/// escrow on first use. Session keys spend from this balance.
+pub fn handler(ctx: Context<DepositSessionEscrow>, amount: u64) -> Result<()> {
+    require!(amount > 0, InstinctFiError::ZeroCoins);
+
+    let escrow = &mut ctx.accounts.session_escrow;
+    if escrow.authority == Pubkey::default() {
+        escrow.authority = ctx.accounts.authority.key();
+        escrow.next_relay_nonce = 0;
+        escrow.bump = ctx.bumps.session_escrow;
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.session_escrow.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    msg!("Session escrow: {} deposited {} lamports", ctx.accounts.authority.key(), amount);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct DepositSessionEscrow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SessionEscrow::INIT_SPACE,
+        seeds = [b"session_escrow", authority.key().as_ref()],
+        bump,
+    )]
+    pub session_escrow: Account<'info, SessionEscrow>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,12 +1,20 @@
 use anchor_lang::prelude::*;
-use crate::state::PollAccount;
+use crate::state::{OrderBook, PollAccount, VoteAccount};
 use crate::errors::InstinctFiError;
 
-/// Edits an existing poll. Only the creator may call this,
-/// and only when the poll has zero votes, is still active, and has not ended.
+/// Edits an existing poll. Only the creator may call this, and only when no
+/// one else holds coins (every vote is still in the creator's `seed_odds`
+/// position), the poll is still active, and has not ended.
 ///
 /// Editable: title, description, category, image_url, option labels, end_time.
 /// Locked: unit_price, creator_investment, fees, treasury.
+///
+/// Option labels are frozen while outcome tokens are outstanding
+/// (`set_collateral > 0`) or bids rest on the order book: their holders
+/// bought a specific outcome, not an option index.
+///
+/// A seeded poll stays editable until a voter buys in; `end_time` must stay
+/// after `start_time`.
 pub fn handler(
     ctx: Context<EditPoll>,
     _poll_id: u64,
@@ -27,9 +35,14 @@ pub fn handler(
     );
     require!(poll.is_active(), InstinctFiError::PollNotActive);
     require!(!poll.is_ended(&clock), InstinctFiError::PollAlreadyEnded);
-
-    let total_votes: u64 = poll.vote_counts.iter().sum();
-    require!(total_votes == 0, InstinctFiError::PollHasVotes);
+    require!(
+        poll.creator_seeded == 0 || ctx.accounts.creator_position.is_some(),
+        InstinctFiError::MissingCreatorPosition
+    );
+    require!(
+        poll.has_no_voter_coins(ctx.accounts.creator_position.as_deref()),
+        InstinctFiError::PollHasVotes
+    );
 
     // ── Validate new inputs ──
     require!(title.len() <= 64, InstinctFiError::TitleTooLong);
@@ -44,6 +57,11 @@ pub fn handler(
         require!(opt.len() <= 32, InstinctFiError::OptionLabelTooLong);
     }
     require!(end_time > clock.unix_timestamp, InstinctFiError::EndTimeInPast);
+    require!(end_time > poll.start_time, InstinctFiError::InvalidStartTime);
+    if options != poll.options {
+        require!(poll.set_collateral == 0, InstinctFiError::OutstandingOutcomeTokens);
+        OrderBook::require_no_orders(&ctx.accounts.order_book, None)?;
+    }
 
     // ── Apply edits ──
     poll.title = title;
@@ -71,4 +89,16 @@ pub struct EditPoll<'info> {
         bump = poll_account.bump,
     )]
     pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: The poll's order book PDA (may not exist) — must be empty to
+    /// relabel options
+    #[account(seeds = [b"order_book", poll_account.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+
+    /// Seeded polls only: the creator position from `seed_odds`
+    #[account(
+        seeds = [b"vote", poll_account.key().as_ref(), creator.key().as_ref()],
+        bump = creator_position.bump,
+    )]
+    pub creator_position: Option<Account<'info, VoteAccount>>,
 }
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, RegionTally};
+use crate::errors::InstinctFiError;
+use crate::events::RegionalResults;
+
+/// Emit a `RegionalResults` event for each RegionTally passed in
+/// `remaining_accounts`, once the civic poll is finished (finalized or,
+/// for ranked polls, fully tallied). Read-only and permissionless; call it
+/// as often as needed to page through many regions. The outcome itself is
+/// never affected: `winning_option` comes from the global tally.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EmitRegionalResults<'info>>,
+    _poll_id: u64,
+) -> Result<()> {
+    let poll = &ctx.accounts.poll_account;
+    let poll_key = poll.key();
+    require!(poll.is_civic(), InstinctFiError::NotACivicPoll);
+    require!(poll.status == PollAccount::STATUS_SETTLED, InstinctFiError::NotSettled);
+
+    for info in ctx.remaining_accounts.iter() {
+        let tally = Account::<RegionTally>::try_from(info)?;
+        require_keys_eq!(tally.poll, poll_key, InstinctFiError::InvalidRegionTally);
+
+        emit!(RegionalResults {
+            poll: poll_key,
+            poll_id: poll.poll_id,
+            region: tally.region,
+            vote_counts: tally.vote_counts.clone(),
+            voters: tally.voters,
+            winning_option: poll.winning_option,
+        });
+    }
+
+    msg!(
+        "Regional results emitted for {} region(s) of poll {}",
+        ctx.remaining_accounts.len(),
+        poll.poll_id
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct EmitRegionalResults<'info> {
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Close voting on a single-choice or approval civic poll after its end time
+/// and record the result (ranked polls use `tally_ranked`).
+///
+/// Permissionless. The final tally is `vote_counts`; `winning_option` is
+/// the option with the most votes (approvals), or 255 when there were no
+/// votes or the top options tied. There is nothing to claim.
+pub fn handler(ctx: Context<FinalizeCivicPoll>, _poll_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll = &mut ctx.accounts.poll_account;
+
+    // ── Guards ──
+    require!(
+        matches!(poll.poll_kind, PollAccount::KIND_CIVIC | PollAccount::KIND_APPROVAL),
+        InstinctFiError::NotACivicPoll
+    );
+    require!(poll.is_active(), InstinctFiError::AlreadySettled);
+    require!(poll.is_ended(&clock), InstinctFiError::PollNotEnded);
+
+    // ── Determine the outcome (255 = no votes or a tie) ──
+    let max_votes = poll.vote_counts.iter().copied().max().unwrap_or(0);
+    let leaders: Vec<usize> = poll
+        .vote_counts
+        .iter()
+        .enumerate()
+        .filter(|(_, &count)| count == max_votes)
+        .map(|(i, _)| i)
+        .collect();
+    poll.winning_option = match (max_votes, leaders.as_slice()) {
+        (0, _) => 255,
+        (_, [winner]) => *winner as u8,
+        _ => 255,
+    };
+    poll.status = PollAccount::STATUS_SETTLED;
+
+    msg!(
+        "Civic poll {} finalized: tallies={:?} winner={}",
+        poll.poll_id,
+        poll.vote_counts,
+        poll.winning_option
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct FinalizeCivicPoll<'info> {
+    pub finalizer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{OrderBook, PollAccount};
+use crate::errors::InstinctFiError;
+
+/// Creates the order book for a poll (permissionless).
+///
+/// The escrow PDA is funded with its rent-exempt minimum so it can receive
+/// bid deposits of any size and pay out down to that floor.
+pub fn handler(ctx: Context<InitOrderBook>, _poll_id: u64) -> Result<()> {
+    require!(ctx.accounts.poll_account.is_active(), InstinctFiError::PollNotActive);
+    require!(!ctx.accounts.poll_account.is_civic(), InstinctFiError::NotAMarketPoll);
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    require!(
+        !ctx.accounts.poll_account.restricts_voters(),
+        InstinctFiError::OrderBookUnavailable
+    );
+
+    let rent_exempt_min = Rent::get()?.minimum_balance(0);
+    let escrow_balance = ctx.accounts.escrow.lamports();
+    if escrow_balance < rent_exempt_min {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.escrow.to_account_info(),
+                },
+            ),
+            rent_exempt_min - escrow_balance,
+        )?;
+    }
+
+    let book = &mut ctx.accounts.order_book;
+    book.poll = ctx.accounts.poll_account.key();
+    book.next_order_id = 0;
+    book.orders = Vec::new();
+    book.escrow_bump = ctx.bumps.escrow;
+    book.bump = ctx.bumps.order_book;
+
+    msg!("Order book created for poll {}", ctx.accounts.poll_account.poll_id);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct InitOrderBook<'info> {
+    /// Pays account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The poll to trade
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// Order book PDA: seeds = ["order_book", poll_account]
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + OrderBook::INIT_SPACE,
+        seeds = [b"order_book", poll_account.key().as_ref()],
+        bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    /// CHECK: Escrow PDA — holds bid lamports and ask proceeds, no data.
+    #[account(
+        mut,
+        seeds = [b"order_escrow", poll_account.key().as_ref()],
+        bump,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
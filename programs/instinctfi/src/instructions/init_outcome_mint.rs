@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Creates the outcome-token mint for one option of a poll.
+///
+/// Each option gets its own 0-decimal SPL mint whose mint authority is the
+/// poll's treasury PDA, so tokens can only be issued against collateral
+/// deposited through `mint_complete_set`. Anyone may pay to create a mint
+/// (permissionless) — all mints must exist before complete sets can be minted.
+pub fn handler(ctx: Context<InitOutcomeMint>, _poll_id: u64, option_index: u8) -> Result<()> {
+    let poll = &ctx.accounts.poll_account;
+
+    // ── Guards ──
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    require!(!poll.is_token_collateral(), InstinctFiError::UnsupportedForTokenPolls);
+    require!((option_index as usize) < poll.options.len(), InstinctFiError::InvalidOption);
+
+    msg!(
+        "Outcome mint for poll {} option {} created: {}",
+        poll.poll_id,
+        option_index,
+        ctx.accounts.outcome_mint.key()
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64, option_index: u8)]
+pub struct InitOutcomeMint<'info> {
+    /// Pays mint account rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The poll the outcome token belongs to
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — mint authority for all outcome tokens of this poll
+    #[account(
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Outcome mint PDA: seeds = ["outcome_mint", poll_account, option_index]
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"outcome_mint", poll_account.key().as_ref(), &[option_index]],
+        bump,
+        mint::decimals = 0,
+        mint::authority = treasury,
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::ReferralAccount;
+
+/// Registers the signer as a referrer. Pass the resulting ReferralAccount as
+/// the optional `referrer` of `create_poll` to earn rewards.
+pub fn handler(ctx: Context<InitReferralAccount>) -> Result<()> {
+    let referral = &mut ctx.accounts.referral_account;
+    referral.referrer = ctx.accounts.referrer.key();
+    referral.referred_volume = 0;
+    referral.referred_count = 0;
+    referral.total_earned = 0;
+    referral.total_withdrawn = 0;
+    referral.bump = ctx.bumps.referral_account;
+
+    msg!("Referral account created for {}", referral.referrer);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct InitReferralAccount<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferralAccount::INIT_SPACE,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump,
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, RegionTally};
+use crate::errors::InstinctFiError;
+
+/// Create the RegionTally for `region` on an attestation-gated civic poll.
+/// Permissionless — anyone (typically the first voter from the region, or
+/// the civic partner ahead of time) pays the rent. Votes from attestations
+/// in `region` fail until it exists.
+pub fn handler(ctx: Context<InitRegionTally>, _poll_id: u64, region: u16) -> Result<()> {
+    let poll = &ctx.accounts.poll_account;
+    require!(poll.is_civic(), InstinctFiError::NotACivicPoll);
+    require!(poll.require_attestation, InstinctFiError::AttestationRequired);
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+
+    let tally = &mut ctx.accounts.region_tally;
+    tally.poll = poll.key();
+    tally.region = region;
+    tally.vote_counts = vec![0u64; poll.options.len()];
+    tally.voters = 0;
+    tally.bump = ctx.bumps.region_tally;
+
+    msg!("RegionTally: region {} opened on poll {}", region, poll.poll_id);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64, region: u16)]
+pub struct InitRegionTally<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RegionTally::INIT_SPACE,
+        seeds = [b"region_tally", poll_account.key().as_ref(), &region.to_le_bytes()],
+        bump,
+    )]
+    pub region_tally: Account<'info, RegionTally>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{Attestation, AttestationIssuer};
+use crate::errors::InstinctFiError;
+
+/// A whitelisted issuer attests that `wallet` belongs to a verified person,
+/// identified on-chain only by `identity_hash`, in region `region`, until
+/// `expires_at`. Re-issuing by the same issuer renews or corrects the
+/// attestation; another issuer cannot overwrite it until it is revoked.
+///
+/// Issuers should derive `identity_hash` so one person always maps to the
+/// same hash (e.g. a salted hash of a national ID) — that is what lets
+/// attestation-gated polls count each person once.
+pub fn handler(
+    ctx: Context<IssueAttestation>,
+    identity_hash: [u8; 32],
+    region: u16,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at > now, InstinctFiError::InvalidAttestationExpiry);
+
+    let issuer_key = ctx.accounts.issuer.key();
+    let attestation = &mut ctx.accounts.attestation;
+    require!(
+        attestation.issuer == Pubkey::default() || attestation.issuer == issuer_key,
+        InstinctFiError::Unauthorized
+    );
+
+    attestation.wallet = ctx.accounts.wallet.key();
+    attestation.issuer = issuer_key;
+    attestation.identity_hash = identity_hash;
+    attestation.region = region;
+    attestation.expires_at = expires_at;
+    attestation.issued_at = now;
+    attestation.bump = ctx.bumps.attestation;
+
+    msg!(
+        "Attestation: {} attested {} (region {}) until {}",
+        issuer_key,
+        attestation.wallet,
+        region,
+        expires_at
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct IssueAttestation<'info> {
+    /// Whitelisted issuer (pays the attestation rent)
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    /// The issuer's whitelist entry — must exist
+    #[account(
+        seeds = [b"issuer", issuer.key().as_ref()],
+        bump = issuer_account.bump,
+    )]
+    pub issuer_account: Account<'info, AttestationIssuer>,
+
+    /// CHECK: Wallet being attested — only its key is used
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = 8 + Attestation::INIT_SPACE,
+        seeds = [b"attestation", wallet.key().as_ref()],
+        bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub system_program: Program<'info, System>,
+}
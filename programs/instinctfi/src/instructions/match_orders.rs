@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Order, OrderBook, PollAccount, VoteAccount};
+use crate::errors::InstinctFiError;
+use super::cancel_order::{credit_position, order_coins};
+
+/// Match crossing orders in the book (permissionless crank).
+///
+/// Price-time priority: the best bid is the highest price, the best ask the
+/// lowest price, ties broken by the lower (earlier) order id. Trades execute
+/// at the resting (earlier) order's price.
+///
+/// Fills only move book records — coins and stake basis from the ask to the
+/// bid, lamports from the bid's escrow to the ask's proceeds. At most
+/// `min(max_fills, MAX_FILLS_PER_CRANK)` fills happen per call.
+///
+/// Fully-filled orders are then settled and removed from the book, freeing
+/// their slots: the cranker passes the owners' wallets (and, for bids, their
+/// existing VoteAccounts) as writable remaining accounts. Orders whose
+/// accounts are missing stay in the book until the next crank or until the
+/// owner collects them with `cancel_order`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MatchOrders<'info>>,
+    _poll_id: u64,
+    max_fills: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll = &ctx.accounts.poll_account;
+    let poll_key = poll.key();
+
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+    require!(!poll.is_ended(&clock), InstinctFiError::PollAlreadyEnded);
+    require!(poll.has_started(&clock), InstinctFiError::PollNotStarted);
+    require!(!poll.restricts_voters(), InstinctFiError::OrderBookUnavailable);
+
+    let num_options = poll.options.len();
+    let fill_limit = max_fills.min(OrderBook::MAX_FILLS_PER_CRANK);
+    let escrow_bump = ctx.accounts.order_book.escrow_bump;
+    let orders = &mut ctx.accounts.order_book.orders;
+    let mut fills: u8 = 0;
+
+    for option_index in 0..num_options as u8 {
+        while fills < fill_limit {
+            let (Some(b), Some(a)) = (
+                best_order(orders, option_index, Order::SIDE_BID),
+                best_order(orders, option_index, Order::SIDE_ASK),
+            ) else {
+                break;
+            };
+            if orders[b].price < orders[a].price {
+                break;
+            }
+
+            // Maker (earlier order) sets the execution price
+            let price = if orders[b].order_id < orders[a].order_id {
+                orders[b].price
+            } else {
+                orders[a].price
+            };
+            let qty = orders[b].remaining.min(orders[a].remaining);
+            let cost = qty.checked_mul(price).ok_or(InstinctFiError::Overflow)?;
+            let stake = (orders[a].escrow_stake as u128)
+                .checked_mul(qty as u128)
+                .ok_or(InstinctFiError::Overflow)?
+                .checked_div(orders[a].remaining as u128)
+                .ok_or(InstinctFiError::Overflow)? as u64;
+
+            let bid = &mut orders[b];
+            bid.remaining -= qty;
+            bid.filled = bid.filled.checked_add(qty).ok_or(InstinctFiError::Overflow)?;
+            bid.escrow_lamports = bid.escrow_lamports
+                .checked_sub(cost)
+                .ok_or(InstinctFiError::Overflow)?;
+            bid.escrow_stake = bid.escrow_stake
+                .checked_add(stake)
+                .ok_or(InstinctFiError::Overflow)?;
+
+            let ask = &mut orders[a];
+            ask.remaining -= qty;
+            ask.filled = ask.filled.checked_add(qty).ok_or(InstinctFiError::Overflow)?;
+            ask.escrow_lamports = ask.escrow_lamports
+                .checked_add(cost)
+                .ok_or(InstinctFiError::Overflow)?;
+            ask.escrow_stake = ask.escrow_stake
+                .checked_sub(stake)
+                .ok_or(InstinctFiError::Overflow)?;
+
+            fills += 1;
+            msg!(
+                "Fill: option={} qty={} price={} bid={} ask={}",
+                option_index,
+                qty,
+                price,
+                orders[b].order_id,
+                orders[a].order_id
+            );
+        }
+    }
+
+    // ── Settle fully-filled orders ──
+    let seeds: &[&[u8]] = &[b"order_escrow", poll_key.as_ref(), &[escrow_bump]];
+    let signer_seeds = &[seeds];
+    let mut settled: u8 = 0;
+    let mut i = 0;
+    while i < orders.len() {
+        let order = &orders[i];
+        if order.remaining > 0 {
+            i += 1;
+            continue;
+        }
+
+        let needs_wallet = order.escrow_lamports > 0;
+        let needs_vote = order_coins(order) > 0 || order.escrow_stake > 0;
+        let wallet = ctx
+            .remaining_accounts
+            .iter()
+            .find(|info| info.key() == order.owner && info.is_writable);
+        let vote_info = ctx
+            .remaining_accounts
+            .iter()
+            .find(|info| needs_vote && is_vote_record(info, &poll_key, &order.owner));
+        if (needs_wallet && wallet.is_none()) || (needs_vote && vote_info.is_none()) {
+            i += 1;
+            continue;
+        }
+
+        if let Some(info) = vote_info {
+            let mut vote = VoteAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            credit_position(poll, &mut vote, order)?;
+            vote.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        }
+        if let Some(wallet) = wallet.filter(|_| needs_wallet) {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.escrow.to_account_info(),
+                        to: wallet.clone(),
+                    },
+                    signer_seeds,
+                ),
+                order.escrow_lamports,
+            )?;
+        }
+
+        msg!("Settled: order={} owner={}", order.order_id, order.owner);
+        orders.swap_remove(i);
+        settled += 1;
+    }
+
+    msg!("MatchOrders: poll={} fills={} settled={}", poll.poll_id, fills, settled);
+    Ok(())
+}
+
+/// Whether `info` is a writable VoteAccount of `owner` on this poll.
+fn is_vote_record(info: &AccountInfo, poll_key: &Pubkey, owner: &Pubkey) -> bool {
+    if info.owner != &crate::ID || !info.is_writable {
+        return false;
+    }
+    let Ok(data) = info.try_borrow_data() else {
+        return false;
+    };
+    VoteAccount::try_deserialize(&mut &data[..])
+        .map(|vote| vote.poll == *poll_key && vote.voter == *owner)
+        .unwrap_or(false)
+}
+
+/// Index of the best open order on one side of an option's book.
+fn best_order(orders: &[Order], option_index: u8, side: u8) -> Option<usize> {
+    orders
+        .iter()
+        .enumerate()
+        .filter(|(_, o)| o.option_index == option_index && o.side == side && o.remaining > 0)
+        .min_by(|(_, x), (_, y)| {
+            let by_price = if side == Order::SIDE_BID {
+                y.price.cmp(&x.price)
+            } else {
+                x.price.cmp(&y.price)
+            };
+            by_price.then(x.order_id.cmp(&y.order_id))
+        })
+        .map(|(i, _)| i)
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct MatchOrders<'info> {
+    /// Anyone can crank matching
+    pub cranker: Signer<'info>,
+
+    /// The poll being traded
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// The poll's order book
+    #[account(
+        mut,
+        seeds = [b"order_book", poll_account.key().as_ref()],
+        bump = order_book.bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    /// CHECK: Escrow PDA — pays settled proceeds and unspent bid lamports
+    #[account(
+        mut,
+        seeds = [b"order_escrow", poll_account.key().as_ref()],
+        bump = order_book.escrow_bump,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
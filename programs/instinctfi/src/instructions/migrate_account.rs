@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use crate::state::{PollAccount, PriceCurve, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// `PollAccount::INIT_SPACE` as first deployed, before any field was appended.
+pub const POLL_V0_SPACE: usize = 1000;
+/// `VoteAccount::INIT_SPACE` as first deployed.
+pub const VOTE_V0_SPACE: usize = 126;
+/// Fixed-size PollAccount v0 fields after `vote_counts`: unit_price through
+/// created_at.
+const POLL_V0_TAIL: usize = 64;
+/// Fixed-size VoteAccount v0 fields after `votes_per_option`: total_staked,
+/// claimed, bump.
+const VOTE_V0_TAIL: usize = 10;
+
+/// Upgrades a PollAccount or VoteAccount created with the first deployed
+/// layout to the current one. Permissionless: `payer` tops up the rent for
+/// the larger account.
+///
+/// Every field added since is appended after the v0 fields, so the account
+/// is migrated by clearing whatever follows the v0 data (an `edit_poll` that
+/// shortened a string leaves stale bytes there), growing it to the current
+/// size with zeroes, then fixing the fields whose zero value is not their
+/// default — a poll gets one flat `PriceCurve` per option. Everything else
+/// decodes as "unset": SOL collateral, no caps, gates or thresholds, market
+/// kind, open since creation, no soft close.
+///
+/// Later layout changes must keep appending fields and extend this
+/// instruction for the previous size.
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let info = ctx.accounts.account.to_account_info();
+    require_keys_eq!(*info.owner, crate::ID, InstinctFiError::InvalidMigration);
+
+    // ── Identify the account and the end of its v0 data ──
+    let (space, v0_end, is_poll) = v0_layout(&info.try_borrow_data()?)?;
+    let old_len = info.data_len();
+
+    // ── Top up rent, clear stale bytes and grow ──
+    let shortfall = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    info.try_borrow_mut_data()?[v0_end..].fill(0);
+    info.realloc(space, true)?;
+
+    // ── Defaults that are not all-zero ──
+    if is_poll {
+        let mut poll = PollAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        poll.price_curves = vec![PriceCurve::default(); poll.options.len()];
+        poll.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    } else {
+        VoteAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    }
+
+    msg!("Migrated {} from {} to {} bytes", info.key(), old_len, space);
+    Ok(())
+}
+
+/// For a v0 account's data: the current account size, the end of its v0
+/// data, and whether it is a PollAccount (else a VoteAccount).
+fn v0_layout(data: &[u8]) -> Result<(usize, usize, bool)> {
+    require!(data.len() >= 8, InstinctFiError::InvalidMigration);
+    let mut body = &data[8..];
+    let (space, tail, is_poll) = if data[..8] == PollAccount::DISCRIMINATOR {
+        require!(data.len() == 8 + POLL_V0_SPACE, InstinctFiError::InvalidMigration);
+        <(u64, Pubkey, String, String, String, String, Vec<String>, Vec<u64>)>::deserialize(&mut body)?;
+        (8 + PollAccount::INIT_SPACE, POLL_V0_TAIL, true)
+    } else if data[..8] == VoteAccount::DISCRIMINATOR {
+        require!(data.len() == 8 + VOTE_V0_SPACE, InstinctFiError::InvalidMigration);
+        <(Pubkey, Pubkey, Vec<u64>)>::deserialize(&mut body)?;
+        (8 + VoteAccount::INIT_SPACE, VOTE_V0_TAIL, false)
+    } else {
+        return err!(InstinctFiError::InvalidMigration);
+    };
+    let v0_end = data.len() - body.len() + tail;
+    require!(v0_end <= data.len(), InstinctFiError::InvalidMigration);
+    Ok((space, v0_end, is_poll))
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// Pays the rent for the added bytes (anyone)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: A PollAccount or VoteAccount in its v0 layout — owner,
+    /// discriminator and size are checked in the handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type PollHeadV0 = (u64, Pubkey, String, String, String, String, Vec<String>, Vec<u64>);
+
+    fn poll_head_v0(title_len: usize, num_options: usize) -> PollHeadV0 {
+        (
+            7,
+            Pubkey::new_unique(),
+            "t".repeat(title_len),
+            "d".repeat(256),
+            "c".repeat(32),
+            "i".repeat(256),
+            vec!["o".repeat(32); num_options],
+            vec![3; num_options],
+        )
+    }
+
+    /// A v0 poll as stored: discriminator, head, the fixed tail fields, then
+    /// whatever bytes follow in the account.
+    fn poll_v0_bytes(head: &PollHeadV0, trailing: u8) -> Vec<u8> {
+        let mut data = PollAccount::DISCRIMINATOR.to_vec();
+        head.serialize(&mut data).unwrap();
+        // unit_price .. creator_reward, status, winning_option, bumps, total_voters, created_at
+        (10u64, 1_000i64, 500u64, 400u64, 8u64, 2u64).serialize(&mut data).unwrap();
+        (0u8, 255u8, 254u8, 253u8, 9u32, 42i64).serialize(&mut data).unwrap();
+        data.resize(8 + POLL_V0_SPACE, trailing);
+        data
+    }
+
+    #[test]
+    fn v0_sizes_match_the_original_layout() {
+        let full = poll_head_v0(64, 6);
+        let mut head = Vec::new();
+        full.serialize(&mut head).unwrap();
+        assert_eq!(head.len() + POLL_V0_TAIL, POLL_V0_SPACE);
+
+        let mut vote = Vec::new();
+        (Pubkey::new_unique(), Pubkey::new_unique(), vec![0u64; 6]).serialize(&mut vote).unwrap();
+        assert_eq!(vote.len() + VOTE_V0_TAIL, VOTE_V0_SPACE);
+    }
+
+    #[test]
+    fn cleared_and_grown_v0_poll_decodes_with_unset_fields() {
+        // A shortened title leaves stale non-zero bytes after the v0 data
+        let head = poll_head_v0(10, 3);
+        let mut data = poll_v0_bytes(&head, 0xAB);
+        let (space, v0_end, is_poll) = v0_layout(&data).unwrap();
+        assert!(is_poll);
+        assert_eq!(data[v0_end - 1], 0); // created_at's high byte
+        assert_eq!(data[v0_end], 0xAB);
+
+        data[v0_end..].fill(0);
+        data.resize(space, 0);
+        let poll = PollAccount::try_deserialize(&mut &data[..]).unwrap();
+
+        assert_eq!(poll.poll_id, 7);
+        assert_eq!(poll.options.len(), 3);
+        assert_eq!(poll.total_voters, 9);
+        assert_eq!(poll.created_at, 42);
+        assert!(poll.price_curves.is_empty());
+        assert!(!poll.is_token_collateral());
+        assert!(!poll.restricts_voters());
+        assert_eq!(poll.poll_kind, PollAccount::KIND_MARKET);
+        assert_eq!(poll.start_time, 0);
+        assert!(!poll.has_soft_close());
+        assert_eq!(poll.voided_refunds, 0);
+    }
+
+    #[test]
+    fn current_size_accounts_are_rejected() {
+        let mut data = poll_v0_bytes(&poll_head_v0(64, 6), 0);
+        data.resize(8 + PollAccount::INIT_SPACE, 0);
+        assert!(v0_layout(&data).is_err());
+        assert!(v0_layout(&[0u8; 8 + POLL_V0_SPACE]).is_err());
+    }
+}
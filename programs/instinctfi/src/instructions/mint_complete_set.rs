@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token, TokenAccount};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Mint `num_sets` complete sets of outcome tokens.
+///
+/// The minter deposits `num_sets × unit_price` lamports into the treasury and
+/// receives `num_sets` tokens of EVERY option. A complete set is always worth
+/// exactly `unit_price`, which lets third parties arbitrage option prices on
+/// external order books against the treasury.
+///
+/// The deposit is tracked in `set_collateral` and never enters `total_pool`,
+/// so complete sets do not affect vote counts or parimutuel payouts.
+///
+/// Remaining accounts: one `[outcome_mint, recipient_token_account]` pair per
+/// poll option, in option order.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintCompleteSet<'info>>,
+    _poll_id: u64,
+    num_sets: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let poll_key = ctx.accounts.poll_account.key();
+    let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let num_options = ctx.accounts.poll_account.options.len();
+    let unit_price = ctx.accounts.poll_account.unit_price;
+
+    // ── Guards ──
+    require!(ctx.accounts.poll_account.is_active(), InstinctFiError::PollNotActive);
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    require!(
+        !ctx.accounts.poll_account.is_ended(&clock),
+        InstinctFiError::PollAlreadyEnded
+    );
+    require!(num_sets > 0, InstinctFiError::ZeroCoins);
+
+    let pairs = outcome_accounts(ctx.remaining_accounts, &poll_key, num_options)?;
+
+    // ── Deposit collateral: minter → treasury PDA ──
+    let collateral = num_sets
+        .checked_mul(unit_price)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.minter.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        ),
+        collateral,
+    )?;
+
+    // ── Mint one token of every option per set ──
+    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
+    let signer_seeds = &[seeds];
+
+    for (mint, destination) in pairs {
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint,
+                    to: destination,
+                    authority: ctx.accounts.treasury.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            num_sets,
+        )?;
+    }
+
+    let poll = &mut ctx.accounts.poll_account;
+    poll.set_collateral = poll.set_collateral
+        .checked_add(collateral)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!(
+        "MintCompleteSet: poll={} sets={} collateral={} lamports",
+        poll.poll_id,
+        num_sets,
+        collateral
+    );
+    Ok(())
+}
+
+/// Validates the `[outcome_mint, token_account]` pairs passed as remaining
+/// accounts — exactly one pair per option, in option order, each mint being
+/// the option's outcome-mint PDA and each token account holding that mint.
+pub(crate) fn outcome_accounts<'info>(
+    remaining: &'info [AccountInfo<'info>],
+    poll_key: &Pubkey,
+    num_options: usize,
+) -> Result<Vec<(AccountInfo<'info>, AccountInfo<'info>)>> {
+    require!(
+        remaining.len() == num_options * 2,
+        InstinctFiError::InvalidOutcomeAccounts
+    );
+
+    let mut pairs = Vec::with_capacity(num_options);
+    for (i, pair) in remaining.chunks_exact(2).enumerate() {
+        let (expected_mint, _) = Pubkey::find_program_address(
+            &[b"outcome_mint", poll_key.as_ref(), &[i as u8]],
+            &crate::ID,
+        );
+        require_keys_eq!(pair[0].key(), expected_mint, InstinctFiError::InvalidOutcomeAccounts);
+
+        let token_account = Account::<TokenAccount>::try_from(&pair[1])?;
+        require_keys_eq!(token_account.mint, expected_mint, InstinctFiError::InvalidOutcomeAccounts);
+
+        pairs.push((pair[0].clone(), pair[1].clone()));
+    }
+    Ok(pairs)
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct MintCompleteSet<'info> {
+    /// Deposits collateral (pays SOL)
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    /// The poll whose outcome tokens are minted
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — receives collateral, signs as mint authority
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
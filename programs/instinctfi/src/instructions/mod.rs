@@ -3,21 +3,119 @@ pub mod create_poll;
 pub mod edit_poll;
 pub mod delete_poll;
 pub mod cast_vote;
+pub mod cast_votes;
 pub mod settle_poll;
 pub mod claim_reward;
 pub mod sweep_dust;
 pub mod refund_tied_poll;
 pub mod admin_settle_poll;
 pub mod admin_edit_poll;
+pub mod init_outcome_mint;
+pub mod mint_complete_set;
+pub mod redeem_complete_set;
+pub mod redeem_winning_tokens;
+pub mod init_order_book;
+pub mod place_order;
+pub mod cancel_order;
+pub mod match_orders;
+pub mod set_price_curves;
+pub mod burn_receipt;
+pub mod set_platform_config;
+pub mod init_referral_account;
+pub mod withdraw_referral_rewards;
+pub mod set_stake_caps;
+pub mod set_liquidity_thresholds;
+pub mod void_poll;
+pub mod refund_voided_poll;
+pub mod seed_odds;
+pub mod sponsor_poll;
+pub mod refund_sponsor;
+pub mod create_session;
+pub mod revoke_session;
+pub mod deposit_session_escrow;
+pub mod withdraw_session_escrow;
+pub mod session_cast_vote;
+pub mod session_claim_reward;
+pub mod relay_cast_vote;
+pub mod set_allowlist_root;
+pub mod verify_allowlist;
+pub mod set_token_gate;
+pub mod create_civic_poll;
+pub mod cast_civic_vote;
+pub mod finalize_civic_poll;
+pub mod cast_ranked_vote;
+pub mod tally_ranked;
+pub mod cast_approval_vote;
+pub mod add_attestation_issuer;
+pub mod remove_attestation_issuer;
+pub mod issue_attestation;
+pub mod revoke_attestation;
+pub mod init_region_tally;
+pub mod emit_regional_results;
+pub mod create_survey;
+pub mod add_survey_question;
+pub mod answer_survey;
+pub mod set_start_time;
+pub mod set_soft_close;
+pub mod migrate_account;
 
 pub use initialize_user::*;
 pub use create_poll::*;
 pub use edit_poll::*;
 pub use delete_poll::*;
 pub use cast_vote::*;
+pub use cast_votes::*;
 pub use settle_poll::*;
 pub use claim_reward::*;
 pub use sweep_dust::*;
 pub use refund_tied_poll::*;
 pub use admin_settle_poll::*;
 pub use admin_edit_poll::*;
+pub use init_outcome_mint::*;
+pub use mint_complete_set::*;
+pub use redeem_complete_set::*;
+pub use redeem_winning_tokens::*;
+pub use init_order_book::*;
+pub use place_order::*;
+pub use cancel_order::*;
+pub use match_orders::*;
+pub use set_price_curves::*;
+pub use burn_receipt::*;
+pub use set_platform_config::*;
+pub use init_referral_account::*;
+pub use withdraw_referral_rewards::*;
+pub use set_stake_caps::*;
+pub use set_liquidity_thresholds::*;
+pub use void_poll::*;
+pub use refund_voided_poll::*;
+pub use seed_odds::*;
+pub use sponsor_poll::*;
+pub use refund_sponsor::*;
+pub use create_session::*;
+pub use revoke_session::*;
+pub use deposit_session_escrow::*;
+pub use withdraw_session_escrow::*;
+pub use session_cast_vote::*;
+pub use session_claim_reward::*;
+pub use relay_cast_vote::*;
+pub use set_allowlist_root::*;
+pub use verify_allowlist::*;
+pub use set_token_gate::*;
+pub use create_civic_poll::*;
+pub use cast_civic_vote::*;
+pub use finalize_civic_poll::*;
+pub use cast_ranked_vote::*;
+pub use tally_ranked::*;
+pub use cast_approval_vote::*;
+pub use add_attestation_issuer::*;
+pub use remove_attestation_issuer::*;
+pub use issue_attestation::*;
+pub use revoke_attestation::*;
+pub use init_region_tally::*;
+pub use emit_regional_results::*;
+pub use create_survey::*;
+pub use add_survey_question::*;
+pub use answer_survey::*;
+pub use set_start_time::*;
+pub use set_soft_close::*;
+pub use migrate_account::*;
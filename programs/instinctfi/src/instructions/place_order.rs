@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{Order, OrderBook, PollAccount, PriceCurve, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// Place a limit order on an option's coins.
+///
+/// Bid: `quantity × price` lamports are escrowed in the order-escrow PDA.
+/// Ask: `quantity` coins are moved out of the seller's VoteAccount into the
+/// order, together with a proportional share of the seller's `total_staked`
+/// (the stake basis travels with the coins so tie refunds stay solvent).
+///
+/// The limit price is the seller's minimum proceeds: an ask only fills at its
+/// own price or at a higher resting bid's.
+///
+/// Orders must be worth at least `OrderBook::MIN_ORDER_LAMPORTS` and rest
+/// until matched by `match_orders` or closed by `cancel_order`. The book opens
+/// at `start_time`, like voting. Polls that cap or vet their voters can't use
+/// the book.
+pub fn handler(
+    ctx: Context<PlaceOrder>,
+    _poll_id: u64,
+    side: u8,
+    option_index: u8,
+    price: u64,
+    quantity: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll = &ctx.accounts.poll_account;
+
+    // ── Guards ──
+    require!(poll.is_active(), InstinctFiError::PollNotActive);
+    require!(!poll.is_token_collateral(), InstinctFiError::UnsupportedForTokenPolls);
+    require!(!poll.is_ended(&clock), InstinctFiError::PollAlreadyEnded);
+    require!(poll.has_started(&clock), InstinctFiError::PollNotStarted);
+    require!(!poll.restricts_voters(), InstinctFiError::OrderBookUnavailable);
+    require!((option_index as usize) < poll.options.len(), InstinctFiError::InvalidOption);
+    require!(
+        poll.price_curves.get(option_index as usize).map(|c| c.kind) != Some(PriceCurve::KIND_QUADRATIC),
+        InstinctFiError::QuadraticCoinsNotTradable
+    );
+    require!(price > 0 && quantity > 0, InstinctFiError::InvalidOrder);
+    let notional = quantity
+        .checked_mul(price)
+        .ok_or(InstinctFiError::Overflow)?;
+    require!(notional >= OrderBook::MIN_ORDER_LAMPORTS, InstinctFiError::OrderTooSmall);
+    require!(
+        ctx.accounts.order_book.orders.len() < OrderBook::MAX_ORDERS,
+        InstinctFiError::OrderBookFull
+    );
+
+    let mut escrow_lamports = 0u64;
+    let mut escrow_stake = 0u64;
+
+    if side == Order::SIDE_BID {
+        require!(
+            ctx.accounts.owner.key() != poll.creator,
+            InstinctFiError::CreatorCannotVote
+        );
+        escrow_lamports = notional;
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.escrow.to_account_info(),
+                },
+            ),
+            escrow_lamports,
+        )?;
+    } else if side == Order::SIDE_ASK {
+        let vote = ctx
+            .accounts
+            .vote_account
+            .as_mut()
+            .ok_or(InstinctFiError::InsufficientPosition)?;
+        require!(!vote.claimed, InstinctFiError::AlreadyClaimed);
+
+        let held = vote.votes_per_option[option_index as usize];
+        require!(held >= quantity, InstinctFiError::InsufficientPosition);
+
+        // Stake basis moves with the coins, pro rata over all coins held
+        let total_coins: u64 = vote.votes_per_option.iter().sum();
+        escrow_stake = (vote.total_staked as u128)
+            .checked_mul(quantity as u128)
+            .ok_or(InstinctFiError::Overflow)?
+            .checked_div(total_coins as u128)
+            .ok_or(InstinctFiError::Overflow)? as u64;
+
+        vote.votes_per_option[option_index as usize] = held - quantity;
+        vote.total_staked = vote.total_staked
+            .checked_sub(escrow_stake)
+            .ok_or(InstinctFiError::Overflow)?;
+    } else {
+        return err!(InstinctFiError::InvalidOrder);
+    }
+
+    // ── Record order ──
+    let book = &mut ctx.accounts.order_book;
+    let order_id = book.next_order_id;
+    book.next_order_id = order_id
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+    book.orders.push(Order {
+        order_id,
+        owner: ctx.accounts.owner.key(),
+        side,
+        option_index,
+        price,
+        remaining: quantity,
+        filled: 0,
+        escrow_lamports,
+        escrow_stake,
+    });
+
+    msg!(
+        "PlaceOrder: poll={} order={} side={} option={} price={} qty={}",
+        ctx.accounts.poll_account.poll_id,
+        order_id,
+        side,
+        option_index,
+        price,
+        quantity
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct PlaceOrder<'info> {
+    /// Order owner (pays SOL for bids)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The poll being traded
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// The poll's order book
+    #[account(
+        mut,
+        seeds = [b"order_book", poll_account.key().as_ref()],
+        bump = order_book.bump,
+    )]
+    pub order_book: Account<'info, OrderBook>,
+
+    /// CHECK: Escrow PDA — receives bid lamports
+    #[account(
+        mut,
+        seeds = [b"order_escrow", poll_account.key().as_ref()],
+        bump = order_book.escrow_bump,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    /// Owner's vote record — required for asks (source of the coins)
+    #[account(
+        mut,
+        seeds = [b"vote", poll_account.key().as_ref(), owner.key().as_ref()],
+        bump = vote_account.bump,
+    )]
+    pub vote_account: Option<Account<'info, VoteAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+use crate::pricing;
+use super::mint_complete_set::outcome_accounts;
+
+/// Burn `num_sets` complete sets of outcome tokens for their full collateral.
+///
+/// The redeemer burns one token of every option per set and receives
+/// `num_sets × unit_price` lamports back from the treasury. Available at any
+/// time until a winner is declared (including tied polls and polls settled
+/// with no votes). Once a winner exists use `redeem_winning_tokens` instead.
+/// The payout is fixed at mint time (see `pricing::quote_redeem`).
+///
+/// Remaining accounts: one `[outcome_mint, redeemer_token_account]` pair per
+/// poll option, in option order.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemCompleteSet<'info>>,
+    _poll_id: u64,
+    num_sets: u64,
+) -> Result<()> {
+    let poll_key = ctx.accounts.poll_account.key();
+    let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let num_options = ctx.accounts.poll_account.options.len();
+    let winning_option = ctx.accounts.poll_account.winning_option;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+
+    // ── Guards ──
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    require!(winning_option == 255, InstinctFiError::AlreadySettled);
+    require!(num_sets > 0, InstinctFiError::ZeroCoins);
+
+    let payout = pricing::quote_redeem(&ctx.accounts.poll_account, num_sets)?;
+    require!(payout <= set_collateral, InstinctFiError::TreasuryInsufficient);
+
+    let pairs = outcome_accounts(ctx.remaining_accounts, &poll_key, num_options)?;
+
+    // ── Burn one token of every option per set ──
+    for (mint, source) in pairs {
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint,
+                    from: source,
+                    authority: ctx.accounts.redeemer.to_account_info(),
+                },
+            ),
+            num_sets,
+        )?;
+    }
+
+    // ── Return collateral: treasury PDA → redeemer ──
+    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
+    let signer_seeds = &[seeds];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.redeemer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        payout,
+    )?;
+
+    let poll = &mut ctx.accounts.poll_account;
+    poll.set_collateral = poll.set_collateral
+        .checked_sub(payout)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!(
+        "RedeemCompleteSet: poll={} sets={} payout={} lamports",
+        poll.poll_id,
+        num_sets,
+        payout
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct RedeemCompleteSet<'info> {
+    /// Token owner burning the sets and receiving collateral
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    /// The poll whose outcome tokens are redeemed
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — collateral source
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
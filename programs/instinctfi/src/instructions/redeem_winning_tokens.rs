@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+use crate::pricing;
+
+/// Burn `amount` winning outcome tokens for `unit_price` lamports each.
+///
+/// After settlement only the winning option's token carries value — it is
+/// backed by the full collateral of the complete set it was minted with.
+/// Losing tokens are worthless and are never redeemable. The payout is
+/// fixed at mint time (see `pricing::quote_redeem`), so there is no slippage
+/// to guard against.
+pub fn handler(ctx: Context<RedeemWinningTokens>, _poll_id: u64, amount: u64) -> Result<()> {
+    let poll_key = ctx.accounts.poll_account.key();
+    let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let status = ctx.accounts.poll_account.status;
+    let winning_option = ctx.accounts.poll_account.winning_option;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+
+    // ── Guards ──
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    require!(status == PollAccount::STATUS_SETTLED, InstinctFiError::NotSettled);
+    require!(winning_option != 255, InstinctFiError::NoVotes);
+    require!(amount > 0, InstinctFiError::ZeroCoins);
+
+    let payout = pricing::quote_redeem(&ctx.accounts.poll_account, amount)?;
+    require!(payout <= set_collateral, InstinctFiError::TreasuryInsufficient);
+
+    // ── Burn winning tokens ──
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.winning_mint.to_account_info(),
+                from: ctx.accounts.redeemer_token_account.to_account_info(),
+                authority: ctx.accounts.redeemer.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // ── Pay out collateral: treasury PDA → redeemer ──
+    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
+    let signer_seeds = &[seeds];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.redeemer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        payout,
+    )?;
+
+    let poll = &mut ctx.accounts.poll_account;
+    poll.set_collateral = poll.set_collateral
+        .checked_sub(payout)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!(
+        "RedeemWinningTokens: poll={} option={} amount={} payout={} lamports",
+        poll.poll_id,
+        winning_option,
+        amount,
+        payout
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct RedeemWinningTokens<'info> {
+    /// Token owner burning winning tokens and receiving collateral
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    /// The settled poll
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — collateral source
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Outcome mint of the winning option
+    #[account(
+        mut,
+        seeds = [b"outcome_mint", poll_account.key().as_ref(), &[poll_account.winning_option]],
+        bump,
+    )]
+    pub winning_mint: Account<'info, Mint>,
+
+    /// Redeemer's token account holding winning tokens
+    #[account(
+        mut,
+        token::mint = winning_mint,
+        token::authority = redeemer,
+    )]
+    pub redeemer_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{PollAccount, SponsorAccount};
+use crate::errors::InstinctFiError;
+use crate::vault;
+
+/// Refund a sponsor's contribution and close its SponsorAccount.
+///
+/// Refunds are pro-rata to what each sponsor put in: every sponsor gets back
+/// exactly its recorded `amount`. Allowed when the poll was voided
+/// (`void_poll`), settled without any votes (no winners to pay), or ended in a
+/// tie — the state in which `refund_tied_poll` refunds voters; the amount then
+/// also leaves `total_pool`. Sponsored polls cannot be deleted, so the poll
+/// always still exists; the record's
+/// seeds include the poll's `created_at`, so it can only match the poll it
+/// sponsored.
+pub fn handler(ctx: Context<RefundSponsor>, _poll_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll_key = ctx.accounts.poll_account.key();
+    let amount = ctx.accounts.sponsor_account.amount;
+
+    // ── Is the sponsorship refundable? ──
+    let poll = &ctx.accounts.poll_account;
+    let no_votes = poll.vote_counts.iter().all(|&c| c == 0);
+    let tied = poll.is_active() && poll.is_ended(&clock) && poll.is_tied();
+    require!(
+        poll.status == PollAccount::STATUS_VOIDED
+            || (poll.status == PollAccount::STATUS_SETTLED && no_votes)
+            || tied,
+        InstinctFiError::SponsorNotRefundable
+    );
+
+    // ── Transfer refund: treasury → sponsor ──
+    let token = vault::token_leg(
+        poll,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.sponsor_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    let available = vault::available(&ctx.accounts.treasury, token.as_ref(), poll.set_collateral)?;
+    require!(available >= amount, InstinctFiError::TreasuryInsufficient);
+
+    vault::withdraw(
+        &poll_key,
+        poll.treasury_bump,
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.sponsor.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
+        amount,
+    )?;
+
+    // ── This sponsorship no longer needs reserving ──
+    let poll = &mut ctx.accounts.poll_account;
+    poll.total_sponsored = poll.total_sponsored.saturating_sub(amount);
+    if tied {
+        // Unsettled: keep the pool a later settlement divides solvent
+        poll.total_pool = poll.total_pool.saturating_sub(amount);
+    }
+
+    // sponsor_account is closed automatically via `close = sponsor` constraint
+    msg!(
+        "RefundSponsor: sponsor {} refunded {} lamports from poll {}",
+        ctx.accounts.sponsor.key(),
+        amount,
+        poll_key
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct RefundSponsor<'info> {
+    /// The sponsor reclaiming its contribution
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    /// The sponsored poll
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// The sponsorship being refunded — closed, rent returned to sponsor
+    #[account(
+        mut,
+        close = sponsor,
+        seeds = [
+            b"sponsor",
+            poll_account.key().as_ref(),
+            sponsor.key().as_ref(),
+            &poll_account.created_at.to_le_bytes(),
+        ],
+        bump = sponsor_account.bump,
+        has_one = sponsor @ InstinctFiError::Unauthorized,
+    )]
+    pub sponsor_account: Account<'info, SponsorAccount>,
+
+    /// CHECK: Treasury PDA — source of the refund
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: sponsor's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = sponsor,
+        token::token_program = token_program,
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use crate::state::{PollAccount, VoteAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{OrderBook, PollAccount, VoteAccount};
 use crate::errors::InstinctFiError;
+use crate::vault;
 
 /// CRIT-03 FIX: Refund a voter when a poll ended in a tie.
 ///
@@ -23,7 +24,9 @@ pub fn handler(ctx: Context<RefundTiedPoll>, _poll_id: u64) -> Result<()> {
     let treasury_bump = ctx.accounts.poll_account.treasury_bump;
     let status = ctx.accounts.poll_account.status;
     let end_time = ctx.accounts.poll_account.end_time;
-    let vote_counts = ctx.accounts.poll_account.vote_counts.clone();
+    let no_votes = ctx.accounts.poll_account.vote_counts.iter().all(|&c| c == 0);
+    let tied = ctx.accounts.poll_account.is_tied();
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
 
     // ── Guards ──
     // Poll must still be active (settle_poll was blocked by TiedVote)
@@ -32,36 +35,35 @@ pub fn handler(ctx: Context<RefundTiedPoll>, _poll_id: u64) -> Result<()> {
     require!(clock.unix_timestamp >= end_time, InstinctFiError::PollNotEnded);
     // Voter must not have claimed yet
     require!(!ctx.accounts.vote_account.claimed, InstinctFiError::AlreadyClaimed);
+    OrderBook::require_no_orders(&ctx.accounts.order_book, Some(&ctx.accounts.voter.key()))?;
 
     // ── Verify tie condition ──
-    let max_votes = vote_counts.iter().copied().max().unwrap_or(0);
-    require!(max_votes > 0, InstinctFiError::NoVotes);
-    let tied_count = vote_counts.iter().filter(|&&c| c == max_votes).count();
-    require!(tied_count > 1, InstinctFiError::NotATie);
+    require!(!no_votes, InstinctFiError::NoVotes);
+    require!(tied, InstinctFiError::NotATie);
 
     // ── Calculate refund ──
     let refund_amount = ctx.accounts.vote_account.total_staked;
     require!(refund_amount > 0, InstinctFiError::NoVotes);
 
-    // Ensure treasury has enough (preserve rent-exempt minimum)
-    let rent = Rent::get()?;
-    let rent_exempt_min = rent.minimum_balance(0);
-    let available = ctx.accounts.treasury.lamports().saturating_sub(rent_exempt_min);
+    // Ensure treasury has enough (SOL: preserve rent-exempt minimum)
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    let available = vault::available(&ctx.accounts.treasury, token.as_ref(), set_collateral)?;
     require!(available >= refund_amount, InstinctFiError::TreasuryInsufficient);
 
     // ── Transfer refund ──
-    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
-    let signer_seeds = &[seeds];
-
-    system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.treasury.to_account_info(),
-                to: ctx.accounts.voter.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    vault::withdraw(
+        &poll_key,
+        treasury_bump,
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.voter.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
         refund_amount,
     )?;
 
@@ -112,4 +114,33 @@ pub struct RefundTiedPoll<'info> {
     pub treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: The poll's order book PDA (may not exist) — claims wait until
+    /// the voter's orders are collected with `cancel_order`
+    #[account(seeds = [b"order_book", poll_account.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: voter's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = voter,
+        token::token_program = token_program,
+    )]
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{OrderBook, PollAccount, VoteAccount};
+use crate::errors::InstinctFiError;
+use crate::vault;
+
+/// Refund a voter's full stake from a poll that was voided for missing its
+/// liquidity thresholds (see `void_poll`).
+///
+/// Safety invariants:
+/// - Poll must be VOIDED
+/// - Voter must not have already claimed (prevents double-refund)
+/// - Refund amount = voter's `total_staked` from their VoteAccount (a record
+///   with nothing staked is still marked, so `voided_refunds` can reach
+///   `total_voters` and unlock `sweep_dust`)
+/// - The creator's `seed_odds` position is refunded like any record but not
+///   counted in `voided_refunds`, as it is not counted in `total_voters`
+pub fn handler(ctx: Context<RefundVoidedPoll>, _poll_id: u64) -> Result<()> {
+    // ── Read immutable data ──
+    let poll_key = ctx.accounts.poll_account.key();
+    let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let status = ctx.accounts.poll_account.status;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+
+    // ── Guards ──
+    require!(status == PollAccount::STATUS_VOIDED, InstinctFiError::NotVoided);
+    require!(!ctx.accounts.vote_account.claimed, InstinctFiError::AlreadyClaimed);
+    OrderBook::require_no_orders(&ctx.accounts.order_book, Some(&ctx.accounts.voter.key()))?;
+
+    // ── Calculate refund ──
+    let refund_amount = ctx.accounts.vote_account.total_staked;
+
+    // Ensure treasury has enough (SOL: preserve rent-exempt minimum)
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.voter_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    let available = vault::available(&ctx.accounts.treasury, token.as_ref(), set_collateral)?;
+    require!(available >= refund_amount, InstinctFiError::TreasuryInsufficient);
+
+    // ── Transfer refund ──
+    if refund_amount > 0 {
+        vault::withdraw(
+            &poll_key,
+            treasury_bump,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.voter.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            token.as_ref(),
+            refund_amount,
+        )?;
+    }
+
+    // Mark as claimed to prevent double-refund
+    ctx.accounts.vote_account.claimed = true;
+    count_voided_refund(&mut ctx.accounts.poll_account, &ctx.accounts.voter.key())?;
+
+    msg!(
+        "RefundVoidedPoll: voter {} refunded {} lamports from poll {}",
+        ctx.accounts.voter.key(),
+        refund_amount,
+        _poll_id
+    );
+    Ok(())
+}
+
+/// Counts a refunded record towards `voided_refunds`, unless it is the
+/// creator's seed position.
+pub(crate) fn count_voided_refund(poll: &mut PollAccount, voter: &Pubkey) -> Result<()> {
+    if *voter != poll.creator {
+        poll.voided_refunds = poll.voided_refunds
+            .checked_add(1)
+            .ok_or(InstinctFiError::Overflow)?;
+    }
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct RefundVoidedPoll<'info> {
+    /// The voter requesting their refund
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    /// The voided poll
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// The voter's vote account for this poll
+    #[account(
+        mut,
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = vote_account.bump,
+        constraint = vote_account.voter == voter.key() @ InstinctFiError::Unauthorized,
+        constraint = vote_account.poll == poll_account.key() @ InstinctFiError::Unauthorized,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    /// CHECK: Treasury PDA — source of refund
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The poll's order book PDA (may not exist) — claims wait until
+    /// the voter's orders are collected with `cancel_order`
+    #[account(seeds = [b"order_book", poll_account.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: voter's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = voter,
+        token::token_program = token_program,
+    )]
+    pub voter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    fn voided_poll(creator: Pubkey, total_voters: u32) -> PollAccount {
+        let mut data = PollAccount::DISCRIMINATOR.to_vec();
+        data.resize(8 + PollAccount::INIT_SPACE, 0);
+        let mut poll = PollAccount::try_deserialize(&mut &data[..]).unwrap();
+        poll.creator = creator;
+        poll.status = PollAccount::STATUS_VOIDED;
+        poll.total_voters = total_voters;
+        poll
+    }
+
+    #[test]
+    fn seed_position_refund_does_not_unlock_the_sweep() {
+        let creator = Pubkey::new_unique();
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut poll = voided_poll(creator, 2);
+
+        count_voided_refund(&mut poll, &creator).unwrap();
+        count_voided_refund(&mut poll, &alice).unwrap();
+        assert_eq!(poll.voided_refunds, 1);
+        assert!(!poll.voided_refunds_complete()); // bob's stake is still owed
+
+        count_voided_refund(&mut poll, &bob).unwrap();
+        assert!(poll.voided_refunds_complete());
+    }
+
+    #[test]
+    fn unseeded_poll_unlocks_after_every_voter() {
+        let mut poll = voided_poll(Pubkey::new_unique(), 1);
+        assert!(!poll.voided_refunds_complete());
+        count_voided_refund(&mut poll, &Pubkey::new_unique()).unwrap();
+        assert!(poll.voided_refunds_complete());
+    }
+}
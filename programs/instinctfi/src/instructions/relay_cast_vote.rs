@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_lang::solana_program::sysvar;
+use crate::state::{AllowlistPass, PollAccount, SessionEscrow, VoteAccount, UserAccount};
+use crate::errors::InstinctFiError;
+use crate::pricing;
+use crate::allowlist;
+use crate::token_gate;
+use crate::relay::{self, RelayIntent};
+use super::cast_vote::{apply_soft_close, init_vote_record, record_coins, record_user_stake, require_open_for_votes};
+use super::session_cast_vote::pay_from_escrow;
+
+/// Submit a vote the voter signed off-chain, so they need no SOL for fees.
+///
+/// The transaction must carry an ed25519 precompile instruction, placed
+/// immediately before this one, verifying the voter's signature over
+/// `intent.message()`. The intent must target this poll, be unexpired and
+/// carry the voter escrow's next nonce, which is then consumed — so each
+/// signed intent executes at most once. The stake is paid from the voter's
+/// SessionEscrow; the relayer pays fees and, on a first vote, the
+/// VoteAccount rent. SOL polls only.
+pub fn handler(ctx: Context<RelayCastVote>, _poll_id: u64, intent: RelayIntent) -> Result<()> {
+    let clock = Clock::get()?;
+    let voter_key = ctx.accounts.voter.key();
+    let poll_key = ctx.accounts.poll_account.key();
+
+    // ── Intent & signature ──
+    require!(intent.poll == poll_key, InstinctFiError::IntentPollMismatch);
+    require!(clock.unix_timestamp < intent.expires_at, InstinctFiError::IntentExpired);
+    require!(
+        intent.nonce == ctx.accounts.session_escrow.next_relay_nonce,
+        InstinctFiError::InvalidNonce
+    );
+    relay::require_ed25519_signature(
+        &ctx.accounts.instructions_sysvar.to_account_info(),
+        &voter_key,
+        &intent.message()?,
+    )?;
+    let escrow = &mut ctx.accounts.session_escrow;
+    escrow.next_relay_nonce = escrow.next_relay_nonce
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    // ── Vote guards ──
+    require_open_for_votes(&ctx.accounts.poll_account, &voter_key, &clock)?;
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    require!(
+        (intent.option_index as usize) < ctx.accounts.poll_account.options.len(),
+        InstinctFiError::InvalidOption
+    );
+    require!(intent.num_coins > 0, InstinctFiError::ZeroCoins);
+
+    let held = pricing::held_coins(&ctx.accounts.vote_account, intent.option_index);
+    let cost = pricing::quote_cast_vote(
+        &ctx.accounts.poll_account,
+        intent.option_index,
+        intent.num_coins,
+        held,
+    )?
+    .total_cost;
+    require!(cost <= intent.max_total_cost, InstinctFiError::SlippageExceeded);
+
+    // ── Move SOL: voter escrow → treasury PDA ──
+    pay_from_escrow(
+        &ctx.accounts.session_escrow.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        cost,
+    )?;
+
+    // ── Update poll, VoteAccount & user stats ──
+    let poll = &mut ctx.accounts.poll_account;
+    let vote_account = &mut ctx.accounts.vote_account;
+    init_vote_record(poll, vote_account, poll_key, voter_key, ctx.bumps.vote_account)?;
+    record_coins(poll, vote_account, intent.option_index as usize, intent.num_coins, cost)?;
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    token_gate::require_token_gate(poll, &ctx.accounts.gate_token_account, &voter_key)?;
+    apply_soft_close(poll, poll_key, voter_key, cost, &clock)?;
+    record_user_stake(&mut ctx.accounts.user_account, intent.num_coins, cost)?;
+
+    msg!(
+        "RelayVote: {} coins on option {} for poll {} by {} (nonce {}), cost={} lamports",
+        intent.num_coins,
+        intent.option_index,
+        ctx.accounts.poll_account.poll_id,
+        voter_key,
+        intent.nonce,
+        cost
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct RelayCastVote<'info> {
+    /// Relayer submitting the transaction (pays fees and VoteAccount rent)
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Voter who signed the intent — verified via the ed25519 instruction
+    pub voter: UncheckedAccount<'info>,
+
+    /// Voter's escrow — pays for the coins and tracks the relay nonce
+    #[account(
+        mut,
+        seeds = [b"session_escrow", voter.key().as_ref()],
+        bump = session_escrow.bump,
+    )]
+    pub session_escrow: Account<'info, SessionEscrow>,
+
+    /// Voter's user profile
+    #[account(
+        mut,
+        seeds = [b"user", voter.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// The poll being voted on
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA for this poll — receives SOL
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Voter's vote record on this poll
+    #[account(
+        init_if_needed,
+        payer = relayer,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    /// CHECK: Instructions sysvar — used to read the ed25519 instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Allowlisted polls only: the voter's AllowlistPass
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// Token-gated polls only: voter's token account for the poll's gate mint
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{AttestationIssuer, PLATFORM_ADMIN};
+use crate::errors::InstinctFiError;
+
+/// Remove an issuer from the whitelist. Attestations it issued stop being
+/// accepted immediately, since votes must present the issuer's entry.
+pub fn handler(ctx: Context<RemoveAttestationIssuer>) -> Result<()> {
+    // issuer_account is closed automatically via `close = admin` constraint
+    msg!("Attestation issuer {} removed", ctx.accounts.issuer_account.issuer);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct RemoveAttestationIssuer<'info> {
+    /// The platform admin — ONLY this wallet can remove issuers.
+    #[account(
+        mut,
+        constraint = admin.key() == PLATFORM_ADMIN @ InstinctFiError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"issuer", issuer_account.issuer.as_ref()],
+        bump = issuer_account.bump,
+    )]
+    pub issuer_account: Account<'info, AttestationIssuer>,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::{Attestation, PLATFORM_ADMIN};
+use crate::errors::InstinctFiError;
+
+/// Revoke an attestation. Its issuer or PLATFORM_ADMIN may call this; the
+/// account is closed and its rent returned to the caller. Identity votes
+/// already recorded stay in place, so revoking and re-issuing to another
+/// wallet does not let the identity vote twice on the same poll.
+pub fn handler(ctx: Context<RevokeAttestation>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let attestation = &ctx.accounts.attestation;
+    require!(
+        authority == attestation.issuer || authority == PLATFORM_ADMIN,
+        InstinctFiError::Unauthorized
+    );
+
+    // attestation is closed automatically via `close = authority` constraint
+    msg!("Attestation for {} revoked by {}", attestation.wallet, authority);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    /// The attestation's issuer or PLATFORM_ADMIN
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"attestation", attestation.wallet.as_ref()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::SessionAccount;
+use crate::errors::InstinctFiError;
+
+/// Revoke a session key early (or clean up an expired one). The session
+/// account is closed and its rent returned to the authority.
+pub fn handler(ctx: Context<RevokeSession>) -> Result<()> {
+    // session_account is closed automatically via `close = authority` constraint
+    msg!(
+        "Session: {} revoked key {}",
+        ctx.accounts.authority.key(),
+        ctx.accounts.session_account.session_key
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"session", authority.key().as_ref(), session_account.session_key.as_ref()],
+        bump = session_account.bump,
+        has_one = authority @ InstinctFiError::Unauthorized,
+    )]
+    pub session_account: Account<'info, SessionAccount>,
+}
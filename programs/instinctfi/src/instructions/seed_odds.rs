@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, VoteAccount};
+use crate::errors::InstinctFiError;
+use crate::pricing;
+
+/// Seed initial odds: convert part of the creator's pool seed into coins.
+///
+/// `coins[i]` coins are placed on option `i`, priced on each option's
+/// bonding curve exactly like a vote. No lamports move — the cost is taken
+/// from the pool seed (the 98% of `creator_investment` already in
+/// `total_pool`) and may not exceed it.
+///
+/// The coins are recorded in the creator's position: the VoteAccount at
+/// `["vote", poll, creator]`. `claim_reward`, `refund_tied_poll` and
+/// `refund_voided_poll` treat it like any voter's record. The creator still
+/// cannot `cast_vote`, and the position does not count towards
+/// `total_voters`.
+///
+/// Creator only, once, while the poll has zero votes — call it after any
+/// other pre-vote setup (`edit_poll`, `set_price_curves`, …) since seeded
+/// coins count as votes.
+pub fn handler(ctx: Context<SeedOdds>, _poll_id: u64, coins: Vec<u64>) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll_key = ctx.accounts.poll_account.key();
+    let creator_key = ctx.accounts.creator.key();
+    let poll = &mut ctx.accounts.poll_account;
+
+    // ── Permission & safety checks ──
+    require!(poll.creator == creator_key, InstinctFiError::UnauthorizedNotCreator);
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    poll.require_editable(&clock)?;
+    require!(coins.len() == poll.options.len(), InstinctFiError::OptionCountMismatch);
+
+    // ── Price each option's seed on its curve and tally it ──
+    let mut total_cost: u64 = 0;
+    for (i, &n) in coins.iter().enumerate() {
+        if n == 0 {
+            continue;
+        }
+        let cost = pricing::quote_cast_vote(poll, i as u8, n, 0)?.total_cost;
+        poll.vote_counts[i] = poll.vote_counts[i]
+            .checked_add(n)
+            .ok_or(InstinctFiError::Overflow)?;
+        total_cost = total_cost
+            .checked_add(cost)
+            .ok_or(InstinctFiError::Overflow)?;
+    }
+    require!(total_cost > 0, InstinctFiError::ZeroCoins);
+    require!(total_cost <= poll.pool_seed(), InstinctFiError::SeedExceedsPool);
+    poll.creator_seeded = total_cost;
+
+    // ── Creator position ──
+    let position = &mut ctx.accounts.creator_position;
+    position.poll = poll_key;
+    position.voter = creator_key;
+    position.votes_per_option = coins;
+    position.total_staked = total_cost;
+    position.claimed = false;
+    position.bump = ctx.bumps.creator_position;
+    position.ranking = Vec::new();
+    position.tallied_round = 0;
+
+    msg!(
+        "Poll {} seeded by creator: {:?} coins, {} lamports of pool seed",
+        poll.poll_id,
+        position.votes_per_option,
+        total_cost
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SeedOdds<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// Creator position: the creator's vote record for this poll
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), creator.key().as_ref()],
+        bump,
+    )]
+    pub creator_position: Account<'info, VoteAccount>,
+
+    pub system_program: Program<'info, System>,
+}
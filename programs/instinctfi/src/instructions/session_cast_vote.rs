@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::{AllowlistPass, PollAccount, SessionAccount, SessionEscrow, VoteAccount, UserAccount};
+use crate::errors::InstinctFiError;
+use crate::pricing;
+use crate::allowlist;
+use crate::token_gate;
+use super::cast_vote::{apply_soft_close, init_vote_record, record_coins, record_user_stake, require_open_for_votes};
+
+/// `cast_vote` signed by a session key instead of the wallet.
+///
+/// The session must be unexpired, scoped to this poll, and have room under
+/// its spend cap. The cost is paid from the authority's SessionEscrow and
+/// the coins are recorded on the authority's VoteAccount and profile, exactly
+/// as if the authority had voted. The session key only pays transaction fees
+/// and, on a first vote, the VoteAccount rent. SOL polls only; receipts and
+/// referrals need the wallet-signed `cast_vote`.
+pub fn handler(
+    ctx: Context<SessionCastVote>,
+    _poll_id: u64,
+    option_index: u8,
+    num_coins: u64,
+    max_total_cost: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let authority_key = ctx.accounts.authority.key();
+    let poll_key = ctx.accounts.poll_account.key();
+
+    // ── Guards ──
+    ctx.accounts.session_account.require_usable(&ctx.accounts.poll_account, &poll_key, clock.unix_timestamp)?;
+    require_open_for_votes(&ctx.accounts.poll_account, &authority_key, &clock)?;
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    require!(
+        (option_index as usize) < ctx.accounts.poll_account.options.len(),
+        InstinctFiError::InvalidOption
+    );
+    require!(num_coins > 0, InstinctFiError::ZeroCoins);
+
+    let held = pricing::held_coins(&ctx.accounts.vote_account, option_index);
+    let cost = pricing::quote_cast_vote(&ctx.accounts.poll_account, option_index, num_coins, held)?
+        .total_cost;
+    require!(cost <= max_total_cost, InstinctFiError::SlippageExceeded);
+    ctx.accounts.session_account.charge(cost)?;
+
+    // ── Move SOL: escrow → treasury PDA ──
+    pay_from_escrow(
+        &ctx.accounts.session_escrow.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        cost,
+    )?;
+
+    // ── Update poll, VoteAccount & user stats ──
+    let poll = &mut ctx.accounts.poll_account;
+    let vote_account = &mut ctx.accounts.vote_account;
+    init_vote_record(poll, vote_account, poll_key, authority_key, ctx.bumps.vote_account)?;
+    record_coins(poll, vote_account, option_index as usize, num_coins, cost)?;
+    allowlist::require_allowlisted(poll, &ctx.accounts.allowlist_pass, vote_account)?;
+    token_gate::require_token_gate(poll, &ctx.accounts.gate_token_account, &authority_key)?;
+    apply_soft_close(poll, poll_key, authority_key, cost, &clock)?;
+    record_user_stake(&mut ctx.accounts.user_account, num_coins, cost)?;
+
+    msg!(
+        "SessionVote: {} coins on option {} for poll {} by {}, cost={} lamports",
+        num_coins,
+        option_index,
+        ctx.accounts.poll_account.poll_id,
+        authority_key,
+        cost
+    );
+    Ok(())
+}
+
+/// Moves `cost` lamports from a SessionEscrow to the poll treasury. The
+/// program owns the escrow, so it debits it directly; the escrow always
+/// keeps its rent-exempt minimum.
+pub(crate) fn pay_from_escrow(escrow: &AccountInfo, treasury: &AccountInfo, cost: u64) -> Result<()> {
+    let rent_exempt_min = Rent::get()?.minimum_balance(escrow.data_len());
+    let available = escrow.lamports().saturating_sub(rent_exempt_min);
+    require!(available >= cost, InstinctFiError::EscrowInsufficient);
+
+    **escrow.try_borrow_mut_lamports()? -= cost;
+    **treasury.try_borrow_mut_lamports()? += cost;
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SessionCastVote<'info> {
+    /// Ephemeral session key (pays fees and VoteAccount rent)
+    #[account(mut)]
+    pub session_key: Signer<'info>,
+
+    /// CHECK: Wallet that granted the session — bound by the session PDA seeds
+    pub authority: UncheckedAccount<'info>,
+
+    /// The session authorizing `session_key` for `authority`
+    #[account(
+        mut,
+        seeds = [b"session", authority.key().as_ref(), session_key.key().as_ref()],
+        bump = session_account.bump,
+    )]
+    pub session_account: Account<'info, SessionAccount>,
+
+    /// Authority's escrow — pays for the coins
+    #[account(
+        mut,
+        seeds = [b"session_escrow", authority.key().as_ref()],
+        bump = session_escrow.bump,
+    )]
+    pub session_escrow: Account<'info, SessionEscrow>,
+
+    /// Authority's user profile
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// The poll being voted on
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA for this poll — receives SOL
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Authority's vote record on this poll
+    #[account(
+        init_if_needed,
+        payer = session_key,
+        space = 8 + VoteAccount::INIT_SPACE,
+        seeds = [b"vote", poll_account.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Allowlisted polls only: the voter's AllowlistPass
+    #[account(
+        seeds = [b"allowlist", poll_account.key().as_ref(), authority.key().as_ref()],
+        bump = allowlist_pass.bump,
+    )]
+    pub allowlist_pass: Option<Account<'info, AllowlistPass>>,
+
+    /// Token-gated polls only: voter's token account for the poll's gate mint
+    pub gate_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::state::{OrderBook, PollAccount, SessionAccount, VoteAccount, UserAccount};
+use crate::errors::InstinctFiError;
+use crate::vault;
+use super::claim_reward::{record_claim, winning_reward};
+
+/// `claim_reward` signed by a session key instead of the wallet.
+///
+/// The session must be unexpired and scoped to this poll. Winnings are paid
+/// straight to the authority's wallet — never to the session key — and
+/// do not count against the spend cap. SOL polls only.
+pub fn handler(ctx: Context<SessionClaimReward>, _poll_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll_key = ctx.accounts.poll_account.key();
+    let treasury_bump = ctx.accounts.poll_account.treasury_bump;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+
+    // ── Guards & reward ──
+    ctx.accounts.session_account.require_usable(&ctx.accounts.poll_account, &poll_key, clock.unix_timestamp)?;
+    require!(
+        !ctx.accounts.poll_account.is_token_collateral(),
+        InstinctFiError::UnsupportedForTokenPolls
+    );
+    let (reward, user_winning_votes, total_winning_votes) =
+        winning_reward(&ctx.accounts.poll_account, &ctx.accounts.vote_account)?;
+    OrderBook::require_no_orders(&ctx.accounts.order_book, Some(&ctx.accounts.authority.key()))?;
+
+    let available = vault::available(&ctx.accounts.treasury, None, set_collateral)?;
+    require!(available >= reward, InstinctFiError::TreasuryInsufficient);
+
+    // ── Transfer real SOL from treasury → authority ──
+    vault::withdraw(
+        &poll_key,
+        treasury_bump,
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        None,
+        reward,
+    )?;
+
+    record_claim(&mut ctx.accounts.vote_account, &mut ctx.accounts.user_account, reward)?;
+
+    msg!(
+        "SessionClaim: poll={} user={} votes={}/{} reward={} lamports",
+        ctx.accounts.poll_account.poll_id,
+        ctx.accounts.authority.key(),
+        user_winning_votes,
+        total_winning_votes,
+        reward
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SessionClaimReward<'info> {
+    /// Ephemeral session key (pays fees)
+    pub session_key: Signer<'info>,
+
+    /// CHECK: Wallet that granted the session — bound by the session PDA
+    /// seeds; receives the reward
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The session authorizing `session_key` for `authority`
+    #[account(
+        seeds = [b"session", authority.key().as_ref(), session_key.key().as_ref()],
+        bump = session_account.bump,
+    )]
+    pub session_account: Account<'info, SessionAccount>,
+
+    /// Authority's user profile
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// The settled poll
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — SOL source for rewards
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Authority's vote record on this poll
+    #[account(
+        mut,
+        seeds = [b"vote", poll_account.key().as_ref(), authority.key().as_ref()],
+        bump = vote_account.bump,
+    )]
+    pub vote_account: Account<'info, VoteAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The poll's order book PDA (may not exist) — claims wait until
+    /// the voter's orders are collected with `cancel_order`
+    #[account(seeds = [b"order_book", poll_account.key().as_ref()], bump)]
+    pub order_book: UncheckedAccount<'info>,
+}
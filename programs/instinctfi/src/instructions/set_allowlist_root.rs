@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, PLATFORM_ADMIN};
+use crate::errors::InstinctFiError;
+use crate::allowlist;
+
+/// Restrict a poll to an allowlist of eligible voters by setting its merkle
+/// root (all zeroes re-opens it to everyone; civic polls may only clear it
+/// when they require attestations). The creator or PLATFORM_ADMIN
+/// may call this, only while the poll has zero votes, is still active, and
+/// has not ended. Build the root and proofs with `allowlist::AllowlistTree`.
+pub fn handler(ctx: Context<SetAllowlistRoot>, _poll_id: u64, root: [u8; 32]) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+    let authority = ctx.accounts.authority.key();
+
+    // ── Permission & safety checks ──
+    require!(
+        authority == poll.creator || authority == PLATFORM_ADMIN,
+        InstinctFiError::Unauthorized
+    );
+    poll.require_editable(&clock)?;
+    require!(
+        !poll.is_civic() || root != allowlist::OPEN_POLL_ROOT || poll.require_attestation,
+        InstinctFiError::CivicPollNotGated
+    );
+
+    poll.allowlist_root = root;
+
+    msg!("Poll {} allowlist root set by {}", poll.poll_id, authority);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetAllowlistRoot<'info> {
+    /// Poll creator or PLATFORM_ADMIN
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
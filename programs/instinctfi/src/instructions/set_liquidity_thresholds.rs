@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Sets the minimum liquidity a poll needs to settle. Only the creator may
+/// call this, and only while the poll has zero votes, is still active, and
+/// has not ended.
+///
+/// If at end_time `total_pool < min_total_pool` or `total_voters < min_voters`,
+/// settlement is blocked and anyone can `void_poll` instead: the creator
+/// recovers their investment and every voter reclaims `total_staked`.
+/// 0 disables a threshold.
+pub fn handler(
+    ctx: Context<SetLiquidityThresholds>,
+    _poll_id: u64,
+    min_total_pool: u64,
+    min_voters: u32,
+) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+
+    // ── Permission & safety checks ──
+    require!(
+        poll.creator == ctx.accounts.creator.key(),
+        InstinctFiError::UnauthorizedNotCreator
+    );
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    poll.require_editable(&clock)?;
+
+    poll.min_total_pool = min_total_pool;
+    poll.min_voters = min_voters;
+
+    msg!(
+        "Poll {} liquidity thresholds: pool >= {} lamports, voters >= {}",
+        poll.poll_id,
+        min_total_pool,
+        min_voters
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetLiquidityThresholds<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, PLATFORM_ADMIN};
+use crate::errors::InstinctFiError;
+
+/// Creates or updates the platform config. Only PLATFORM_ADMIN may call this.
+///
+/// `referral_share_bps` is the share of the platform fee paid to referrers
+/// (see `referral::pay_referral`); 0 disables referral payouts.
+pub fn handler(ctx: Context<SetPlatformConfig>, referral_share_bps: u16) -> Result<()> {
+    require!(
+        referral_share_bps <= PlatformConfig::MAX_BPS,
+        InstinctFiError::InvalidReferralShare
+    );
+
+    let config = &mut ctx.accounts.platform_config;
+    config.referral_share_bps = referral_share_bps;
+    config.bump = ctx.bumps.platform_config;
+
+    msg!("PlatformConfig: referral share set to {} bps", referral_share_bps);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct SetPlatformConfig<'info> {
+    /// The platform admin — ONLY this wallet can change platform settings.
+    #[account(
+        mut,
+        constraint = admin.key() == PLATFORM_ADMIN @ InstinctFiError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    /// Platform config PDA
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [b"platform_config"],
+        bump,
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub system_program: Program<'info, System>,
+}
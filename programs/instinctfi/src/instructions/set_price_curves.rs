@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, PriceCurve};
+use crate::errors::InstinctFiError;
+
+/// Sets the bonding curve of every option. Only the creator may call this,
+/// and only while the poll has zero votes, is still active, and has not ended
+/// — the same window in which `edit_poll` is allowed.
+///
+/// `curves` must have one entry per option (same index). `unit_price` stays
+/// the price of the first coin on every curve. Quadratic curves price each
+/// voter's coins on their own (n coins cost n² × unit_price) and are all or
+/// nothing: a quadratic-voting poll prices every option that way.
+pub fn handler(ctx: Context<SetPriceCurves>, _poll_id: u64, curves: Vec<PriceCurve>) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+
+    // ── Permission & safety checks ──
+    require!(
+        poll.creator == ctx.accounts.creator.key(),
+        InstinctFiError::UnauthorizedNotCreator
+    );
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    poll.require_editable(&clock)?;
+
+    // ── Validate curves ──
+    require!(
+        curves.len() == poll.options.len(),
+        InstinctFiError::OptionCountMismatch
+    );
+    for curve in &curves {
+        match curve.kind {
+            PriceCurve::KIND_FLAT | PriceCurve::KIND_LINEAR => {}
+            PriceCurve::KIND_EXPONENTIAL => require!(
+                curve.param > 0 && curve.param <= PriceCurve::MAX_EXPONENTIAL_BPS,
+                InstinctFiError::InvalidPriceCurve
+            ),
+            PriceCurve::KIND_QUADRATIC => require!(curve.param == 0, InstinctFiError::InvalidPriceCurve),
+            _ => return err!(InstinctFiError::InvalidPriceCurve),
+        }
+    }
+    let quadratic = curves.iter().filter(|c| c.kind == PriceCurve::KIND_QUADRATIC).count();
+    require!(
+        quadratic == 0 || quadratic == curves.len(),
+        InstinctFiError::InvalidPriceCurve
+    );
+
+    poll.price_curves = curves;
+
+    msg!("Poll {} price curves updated by creator", poll.poll_id);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetPriceCurves<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
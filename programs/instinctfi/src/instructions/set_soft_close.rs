@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Sets the anti-sniping soft-close rule. Only the creator may call this,
+/// and only while the poll has zero votes, is still active, and has not
+/// ended.
+///
+/// - `window`: final seconds before `end_time` in which the rule applies
+///   (0 disables soft close)
+/// - `threshold_bps`: stake landing in the window, summed across votes,
+///   that triggers an extension once it reaches this share of the pool as
+///   it stood when the window opened
+/// - `extension`: seconds added to `end_time` per extension
+/// - `max_extensions`: cap on the number of extensions
+///
+/// Enforced on every vote path by `cast_vote::apply_soft_close`.
+pub fn handler(
+    ctx: Context<SetSoftClose>,
+    _poll_id: u64,
+    window: u32,
+    threshold_bps: u16,
+    extension: u32,
+    max_extensions: u8,
+) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+
+    // ── Permission & safety checks ──
+    require!(
+        poll.creator == ctx.accounts.creator.key(),
+        InstinctFiError::UnauthorizedNotCreator
+    );
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    poll.require_editable(&clock)?;
+    if window > 0 {
+        require!(
+            threshold_bps > 0 && threshold_bps <= 10_000 && extension > 0 && max_extensions > 0,
+            InstinctFiError::InvalidSoftClose
+        );
+    }
+
+    poll.soft_close_window = window;
+    poll.soft_close_threshold_bps = threshold_bps;
+    poll.soft_close_extension = extension;
+    poll.soft_close_max_extensions = max_extensions;
+
+    msg!(
+        "Poll {} soft close: votes >= {} bps in the last {}s extend by {}s (max {})",
+        poll.poll_id,
+        threshold_bps,
+        window,
+        extension,
+        max_extensions
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetSoftClose<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Sets anti-whale caps on a poll. Only the creator may call this, and only
+/// while the poll has zero votes, is still active, and has not ended.
+///
+/// - `max_stake_per_voter`: lamports one voter may stake in total
+/// - `max_coins_per_option`: coins one voter may hold on any single option
+///
+/// 0 disables a cap. Both are enforced on every coin purchase
+/// (`cast_vote`, `cast_votes`) against the voter's VoteAccount.
+pub fn handler(
+    ctx: Context<SetStakeCaps>,
+    _poll_id: u64,
+    max_stake_per_voter: u64,
+    max_coins_per_option: u64,
+) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+
+    // ── Permission & safety checks ──
+    require!(
+        poll.creator == ctx.accounts.creator.key(),
+        InstinctFiError::UnauthorizedNotCreator
+    );
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    poll.require_editable(&clock)?;
+
+    poll.max_stake_per_voter = max_stake_per_voter;
+    poll.max_coins_per_option = max_coins_per_option;
+
+    msg!(
+        "Poll {} stake caps: {} lamports/voter, {} coins/option",
+        poll.poll_id,
+        max_stake_per_voter,
+        max_coins_per_option
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetStakeCaps<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
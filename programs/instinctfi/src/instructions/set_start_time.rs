@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Schedules when voting opens, so a poll can be announced ahead of time
+/// (e.g. a match next week). Only the creator may call this — typically in
+/// the same transaction as `create_poll` — and only while the poll has zero
+/// votes, is still active, and has not ended.
+///
+/// Every vote path rejects votes before `start_time`; `edit_poll` and
+/// `delete_poll` remain available until then. A time in the past opens the
+/// poll immediately.
+pub fn handler(ctx: Context<SetStartTime>, _poll_id: u64, start_time: i64) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+
+    // ── Permission & safety checks ──
+    require!(
+        poll.creator == ctx.accounts.creator.key(),
+        InstinctFiError::UnauthorizedNotCreator
+    );
+    poll.require_editable(&clock)?;
+    require!(start_time < poll.end_time, InstinctFiError::InvalidStartTime);
+
+    poll.start_time = start_time;
+
+    msg!("Poll {} opens for voting at {}", poll.poll_id, start_time);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetStartTime<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+}
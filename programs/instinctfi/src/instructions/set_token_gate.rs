@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Restricts voting to holders of `gate_mint`. Only the creator may call
+/// this, and only while the poll has zero votes, is still active, and has
+/// not ended.
+///
+/// `gate_min_amount` is the minimum balance, in base units. Omitting
+/// `gate_mint` removes the gate. Checked on every coin purchase against the
+/// voter's `gate_token_account` — a presence check, see `token_gate`.
+pub fn handler(
+    ctx: Context<SetTokenGate>,
+    _poll_id: u64,
+    gate_min_amount: u64,
+) -> Result<()> {
+    let poll = &mut ctx.accounts.poll_account;
+    let clock = Clock::get()?;
+
+    // ── Permission & safety checks ──
+    require!(
+        poll.creator == ctx.accounts.creator.key(),
+        InstinctFiError::UnauthorizedNotCreator
+    );
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    poll.require_editable(&clock)?;
+
+    match &ctx.accounts.gate_mint {
+        Some(mint) => {
+            poll.gate_mint = mint.key();
+            poll.gate_min_amount = gate_min_amount;
+        }
+        None => {
+            poll.gate_mint = Pubkey::default();
+            poll.gate_min_amount = 0;
+        }
+    }
+
+    msg!(
+        "Poll {} token gate: mint={} min={}",
+        poll.poll_id,
+        poll.gate_mint,
+        poll.gate_min_amount
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SetTokenGate<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", creator.key().as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// Mint voters must hold (omit to remove the gate)
+    pub gate_mint: Option<InterfaceAccount<'info, Mint>>,
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::{PollAccount, ADMIN_SETTLE_GRACE_SECONDS};
 use crate::errors::InstinctFiError;
+use crate::vault;
+use super::void_poll::void_poll;
 
 /// Settle a poll after its end time using vote-count based resolution.
 ///
@@ -17,6 +19,9 @@ use crate::errors::InstinctFiError;
 /// When two or more options have equal vote counts, settlement is rejected
 /// (TiedVote error). Voters can use `refund_tied_poll` instead.
 ///
+/// A poll that missed its liquidity thresholds is voided instead (as by
+/// `void_poll`, and without waiting out the grace period).
+///
 /// If no votes: refunds entire treasury to creator.
 /// If votes: sends creator_reward to creator; pool stays for winners to claim.
 pub fn handler(ctx: Context<SettlePoll>, _poll_id: u64) -> Result<()> {
@@ -28,14 +33,36 @@ pub fn handler(ctx: Context<SettlePoll>, _poll_id: u64) -> Result<()> {
     let status = ctx.accounts.poll_account.status;
     let end_time = ctx.accounts.poll_account.end_time;
     let creator_reward = ctx.accounts.poll_account.creator_reward;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+    let total_sponsored = ctx.accounts.poll_account.total_sponsored;
     let creator_investment = ctx.accounts.poll_account.creator_investment;
     let vote_counts = ctx.accounts.poll_account.vote_counts.clone();
     let poll_id_val = ctx.accounts.poll_account.poll_id;
 
     // ── Guards ──
     require!(status == PollAccount::STATUS_ACTIVE, InstinctFiError::AlreadySettled);
+    require!(!ctx.accounts.poll_account.is_civic(), InstinctFiError::NotAMarketPoll);
     require!(clock.unix_timestamp >= end_time, InstinctFiError::PollNotEnded);
 
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.token_program,
+    )?;
+
+    // ── Missed its liquidity thresholds: void instead of settling ──
+    if !ctx.accounts.poll_account.meets_liquidity_thresholds() {
+        return void_poll(
+            &mut ctx.accounts.poll_account,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            token.as_ref(),
+        );
+    }
+
     // ── Admin grace period: block vote-count settlement for 7 days ──
     // This gives the platform admin time to use admin_settle_poll for
     // prediction markets. After 7 days, this fallback unlocks.
@@ -45,10 +72,6 @@ pub fn handler(ctx: Context<SettlePoll>, _poll_id: u64) -> Result<()> {
         InstinctFiError::AdminGracePeriodActive
     );
 
-    // ── PDA signer seeds for treasury ──
-    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
-    let signer_seeds = &[seeds];
-
     // ── Determine winner ──
     let mut max_votes: u64 = 0;
     let mut winning_idx: u8 = 0;
@@ -64,17 +87,24 @@ pub fn handler(ctx: Context<SettlePoll>, _poll_id: u64) -> Result<()> {
 
     if !has_votes {
         // No votes — refund entire treasury to creator
-        let treasury_balance = ctx.accounts.treasury.lamports();
+        // Outcome-token collateral and sponsored funds (plus SOL rent while
+        // any remain) stay behind for redemptions and `refund_sponsor`.
+        let held = set_collateral.saturating_add(total_sponsored);
+        let reserved = match (held, token.as_ref()) {
+            (0, _) => 0,
+            (_, None) => held.saturating_add(Rent::get()?.minimum_balance(0)),
+            (_, Some(_)) => held,
+        };
+        let treasury_balance = vault::balance(&ctx.accounts.treasury, token.as_ref())
+            .saturating_sub(reserved);
         if treasury_balance > 0 {
-            system_program::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.system_program.to_account_info(),
-                    system_program::Transfer {
-                        from: ctx.accounts.treasury.to_account_info(),
-                        to: ctx.accounts.creator.to_account_info(),
-                    },
-                    signer_seeds,
-                ),
+            vault::withdraw(
+                &poll_key,
+                treasury_bump,
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.creator.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                token.as_ref(),
                 treasury_balance,
             )?;
         }
@@ -93,26 +123,25 @@ pub fn handler(ctx: Context<SettlePoll>, _poll_id: u64) -> Result<()> {
     require!(tied_count == 1, InstinctFiError::TiedVote);
 
     // BUG-08 FIX: Check treasury has enough lamports for creator_reward
-    // while preserving rent-exempt minimum.
+    // while preserving rent-exempt minimum (SOL) and set collateral.
     if creator_reward > 0 {
-        let rent = Rent::get()?;
-        let rent_exempt_min = rent.minimum_balance(0);
-        let treasury_available = ctx.accounts.treasury.lamports()
-            .saturating_sub(rent_exempt_min);
+        let treasury_available = vault::available(
+            &ctx.accounts.treasury,
+            token.as_ref(),
+            set_collateral,
+        )?;
         require!(
             treasury_available >= creator_reward,
             InstinctFiError::TreasuryInsufficient
         );
 
-        system_program::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.treasury.to_account_info(),
-                    to: ctx.accounts.creator.to_account_info(),
-                },
-                signer_seeds,
-            ),
+        vault::withdraw(
+            &poll_key,
+            treasury_bump,
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            token.as_ref(),
             creator_reward,
         )?;
     }
@@ -165,4 +194,28 @@ pub struct SettlePoll<'info> {
     pub treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: creator's token account receiving refunds and the creator reward
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::{PollAccount, SponsorAccount};
+use crate::errors::InstinctFiError;
+use crate::vault;
+
+/// Sponsor a poll: deposit `amount` into the treasury and add it to
+/// `total_pool`, so winners share it — without taking a position.
+///
+/// Each sponsor has one SponsorAccount per poll recording the cumulative
+/// amount and the latest memo, keyed by the poll's `created_at` so a poll
+/// re-created at the same PDA never matches an old record. A sponsored poll
+/// cannot be deleted; if it is voided, settles without votes or ends in a
+/// tie, the sponsor reclaims its contribution with `refund_sponsor`.
+pub fn handler(ctx: Context<SponsorPoll>, _poll_id: u64, amount: u64, memo: String) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // ── Guards ──
+    require!(ctx.accounts.poll_account.is_active(), InstinctFiError::PollNotActive);
+    require!(!ctx.accounts.poll_account.is_civic(), InstinctFiError::NotAMarketPoll);
+    require!(
+        !ctx.accounts.poll_account.is_ended(&clock),
+        InstinctFiError::PollAlreadyEnded
+    );
+    require!(amount > 0, InstinctFiError::ZeroCoins);
+    require!(memo.len() <= 64, InstinctFiError::MemoTooLong);
+
+    // ── Transfer sponsor → treasury PDA ──
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.sponsor_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    vault::deposit(
+        ctx.accounts.sponsor.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
+        amount,
+    )?;
+
+    // ── Raise the pool ──
+    let poll_key = ctx.accounts.poll_account.key();
+    let poll = &mut ctx.accounts.poll_account;
+    poll.total_pool = poll.total_pool
+        .checked_add(amount)
+        .ok_or(InstinctFiError::Overflow)?;
+    poll.total_sponsored = poll.total_sponsored
+        .checked_add(amount)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    // ── Record the sponsorship ──
+    let record = &mut ctx.accounts.sponsor_account;
+    if record.sponsor == Pubkey::default() {
+        record.poll = poll_key;
+        record.sponsor = ctx.accounts.sponsor.key();
+        record.amount = 0;
+        record.collateral_mint = poll.collateral_mint;
+        record.treasury_bump = poll.treasury_bump;
+        record.poll_created_at = poll.created_at;
+        record.bump = ctx.bumps.sponsor_account;
+    }
+    record.amount = record.amount
+        .checked_add(amount)
+        .ok_or(InstinctFiError::Overflow)?;
+    record.memo = memo;
+
+    msg!(
+        "Sponsor {} added {} lamports to poll {} (pool={})",
+        record.sponsor,
+        amount,
+        poll.poll_id,
+        poll.total_pool
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct SponsorPoll<'info> {
+    /// Sponsor (pays the sponsorship)
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    /// The poll being sponsored
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA for this poll — receives the sponsorship
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Sponsorship record PDA
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = 8 + SponsorAccount::INIT_SPACE,
+        seeds = [
+            b"sponsor",
+            poll_account.key().as_ref(),
+            sponsor.key().as_ref(),
+            &poll_account.created_at.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub sponsor_account: Account<'info, SponsorAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: sponsor's token account paying the sponsorship
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = sponsor,
+        token::token_program = token_program,
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -1,16 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::state::{PollAccount, PLATFORM_ADMIN};
 use crate::errors::InstinctFiError;
+use crate::vault;
 
 /// Sweep residual dust (platform fees + rounding residual) from a settled
-/// poll's treasury to a designated platform admin wallet.
+/// poll's treasury — or a voided one once every voter has been refunded —
+/// to a designated platform admin wallet.
 ///
 /// This instruction addresses two audit findings:
 /// - #48: No mechanism to withdraw accumulated platform fees
 /// - #49: Integer-division truncation leaves dust lamports in treasury
 ///
-/// Can be called by anyone (permissionless crank) once a poll is settled.
+/// Can be called by anyone (permissionless crank) once a poll is settled or
+/// fully refunded.
 /// The treasury keeps its rent-exempt minimum; everything above that is swept.
 pub fn handler(ctx: Context<SweepDust>, _poll_id: u64) -> Result<()> {
     let clock = Clock::get()?;
@@ -18,9 +21,26 @@ pub fn handler(ctx: Context<SweepDust>, _poll_id: u64) -> Result<()> {
     let treasury_bump = ctx.accounts.poll_account.treasury_bump;
     let status = ctx.accounts.poll_account.status;
     let end_time = ctx.accounts.poll_account.end_time;
+    let set_collateral = ctx.accounts.poll_account.set_collateral;
+    let voided = status == PollAccount::STATUS_VOIDED;
+    // Voided, or settled without votes: sponsorships stay refundable, so
+    // never sweep them.
+    let no_votes = ctx.accounts.poll_account.vote_counts.iter().all(|&c| c == 0);
+    let unrefunded_sponsorship = if voided || no_votes {
+        ctx.accounts.poll_account.total_sponsored
+    } else {
+        0
+    };
 
     // ── Guards ──
-    require!(status == PollAccount::STATUS_SETTLED, InstinctFiError::NotSettled);
+    require!(
+        status == PollAccount::STATUS_SETTLED || voided,
+        InstinctFiError::NotSettled
+    );
+    require!(
+        !voided || ctx.accounts.poll_account.voided_refunds_complete(),
+        InstinctFiError::RefundsOutstanding
+    );
 
     // BUG-01 FIX: Enforce a 7-day grace period after poll end_time
     // so all winners have time to claim before dust is swept.
@@ -30,11 +50,21 @@ pub fn handler(ctx: Context<SweepDust>, _poll_id: u64) -> Result<()> {
         InstinctFiError::SweepTooEarly
     );
 
-    // Calculate available dust (everything above rent-exempt minimum)
-    let rent = Rent::get()?;
-    let rent_exempt_min = rent.minimum_balance(0);
-    let treasury_lamports = ctx.accounts.treasury.lamports();
-    let available = treasury_lamports.saturating_sub(rent_exempt_min);
+    // Calculate available dust (everything above rent-exempt minimum,
+    // excluding collateral still backing outstanding outcome tokens and
+    // sponsorships awaiting refund)
+    let token = vault::token_leg(
+        &ctx.accounts.poll_account,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.admin_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    let available = vault::available(
+        &ctx.accounts.treasury,
+        token.as_ref(),
+        set_collateral.saturating_add(unrefunded_sponsorship),
+    )?;
 
     if available == 0 {
         msg!("SweepDust: no dust to sweep for poll {}", _poll_id);
@@ -42,18 +72,13 @@ pub fn handler(ctx: Context<SweepDust>, _poll_id: u64) -> Result<()> {
     }
 
     // Transfer dust to platform admin
-    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
-    let signer_seeds = &[seeds];
-
-    system_program::transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.treasury.to_account_info(),
-                to: ctx.accounts.platform_admin.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    vault::withdraw(
+        &poll_key,
+        treasury_bump,
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.platform_admin.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
         available,
     )?;
 
@@ -84,7 +109,7 @@ pub struct SweepDust<'info> {
     )]
     pub platform_admin: UncheckedAccount<'info>,
 
-    /// The settled poll
+    /// The settled (or fully refunded voided) poll
     #[account(
         seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
         bump = poll_account.bump,
@@ -100,4 +125,28 @@ pub struct SweepDust<'info> {
     pub treasury: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: platform admin's token account receiving the dust
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = platform_admin,
+        token::token_program = token_program,
+    )]
+    pub admin_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
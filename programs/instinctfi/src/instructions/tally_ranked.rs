@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, RankedTally, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// Permissionless, resumable instant-runoff crank for a ranked-choice poll.
+///
+/// Pass ballots (the poll's VoteAccounts, writable) as remaining accounts,
+/// as many per transaction as fit. Each ballot counts once per round for its
+/// highest-ranked option still in the race — or as exhausted — and is
+/// stamped with the round, so re-sent ballots are skipped. Once all
+/// `total_voters` ballots are in, the round is recorded on the RankedTally:
+///
+/// - an option with a strict majority of the non-exhausted ballots wins;
+/// - if every remaining option is tied, the poll is a tie (winner 255);
+/// - otherwise the option with the fewest votes is eliminated (ties for
+///   last place: highest index first) and the next round starts — call the
+///   crank again with every ballot.
+///
+/// When the count finishes, `winning_option` is written and the poll settled.
+pub fn handler(ctx: Context<TallyRanked>, _poll_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll_key = ctx.accounts.poll_account.key();
+
+    // ── Guards ──
+    let poll = &ctx.accounts.poll_account;
+    require!(poll.is_ranked(), InstinctFiError::NotARankedPoll);
+    require!(poll.is_active(), InstinctFiError::AlreadySettled);
+    require!(poll.is_ended(&clock), InstinctFiError::PollNotEnded);
+
+    let num_options = poll.options.len();
+    let total_voters = poll.total_voters;
+    let tally = &mut ctx.accounts.ranked_tally;
+    require!(!tally.complete, InstinctFiError::TallyComplete);
+    if tally.round == 0 {
+        tally.poll = poll_key;
+        tally.round = 1;
+        tally.eliminated = 0;
+        tally.counts = vec![0u64; num_options];
+        tally.exhausted = 0;
+        tally.ballots_counted = 0;
+        tally.round_counts = Vec::new();
+        tally.round_eliminated = Vec::new();
+        tally.bump = ctx.bumps.ranked_tally;
+    }
+
+    // ── Count this batch of ballots into the current round ──
+    for info in ctx.remaining_accounts.iter() {
+        require_keys_eq!(*info.owner, crate::ID, InstinctFiError::InvalidBallot);
+        require!(info.is_writable, InstinctFiError::InvalidBallot);
+        let mut ballot = VoteAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(ballot.poll, poll_key, InstinctFiError::InvalidBallot);
+        if ballot.tallied_round >= tally.round {
+            continue;
+        }
+
+        match tally.top_choice(&ballot.ranking) {
+            Some(option) => {
+                let idx = option as usize;
+                tally.counts[idx] = tally.counts[idx]
+                    .checked_add(1)
+                    .ok_or(InstinctFiError::Overflow)?;
+            }
+            None => {
+                tally.exhausted = tally.exhausted
+                    .checked_add(1)
+                    .ok_or(InstinctFiError::Overflow)?;
+            }
+        }
+        tally.ballots_counted = tally.ballots_counted
+            .checked_add(1)
+            .ok_or(InstinctFiError::Overflow)?;
+
+        ballot.tallied_round = tally.round;
+        ballot.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    }
+
+    if tally.ballots_counted < total_voters {
+        msg!(
+            "TallyRanked: poll {} round {} — {}/{} ballots counted",
+            ctx.accounts.poll_account.poll_id,
+            tally.round,
+            tally.ballots_counted,
+            total_voters
+        );
+        return Ok(());
+    }
+
+    // ── Round complete: majority, tie, or eliminate the last-placed option ──
+    let outcome = decide_round(&tally.counts, tally.eliminated)?;
+
+    let round_counts = tally.counts.clone();
+    tally.round_counts.push(round_counts);
+    match outcome {
+        RoundOutcome::Decided(winner) => {
+            tally.round_eliminated.push(255);
+            tally.complete = true;
+
+            let poll = &mut ctx.accounts.poll_account;
+            poll.winning_option = winner;
+            poll.status = PollAccount::STATUS_SETTLED;
+            msg!(
+                "TallyRanked: poll {} decided in round {} — winner {}",
+                poll.poll_id,
+                tally.round,
+                winner
+            );
+        }
+        RoundOutcome::Eliminate(lowest) => {
+            tally.round_eliminated.push(lowest);
+            tally.eliminated |= 1 << lowest;
+            msg!(
+                "TallyRanked: poll {} round {} eliminated option {}",
+                ctx.accounts.poll_account.poll_id,
+                tally.round,
+                lowest
+            );
+            tally.round = tally.round
+                .checked_add(1)
+                .ok_or(InstinctFiError::Overflow)?;
+            tally.counts = vec![0u64; num_options];
+            tally.exhausted = 0;
+            tally.ballots_counted = 0;
+        }
+    }
+    Ok(())
+}
+
+/// How a fully counted round ends.
+#[derive(Debug, PartialEq)]
+pub(crate) enum RoundOutcome {
+    /// The count is over: the winning option, or 255 for a tie
+    Decided(u8),
+    /// No majority: this option is eliminated and another round follows
+    Eliminate(u8),
+}
+
+/// Decides a round from its per-option `counts` (exhausted ballots are not
+/// in them) and the `eliminated` bitmask — see the rules on `handler`.
+pub(crate) fn decide_round(counts: &[u64], eliminated: u8) -> Result<RoundOutcome> {
+    let remaining: Vec<usize> = (0..counts.len())
+        .filter(|&i| eliminated & (1 << i) == 0)
+        .collect();
+    let active = counts.iter().sum::<u64>();
+    let top = remaining.iter().copied().max_by_key(|&i| (counts[i], std::cmp::Reverse(i)));
+    let lowest = remaining.iter().copied().min_by_key(|&i| (counts[i], std::cmp::Reverse(i)));
+
+    // `remaining` is never empty: the count stops once one option is left
+    let (top, lowest) = match (top, lowest) {
+        (Some(top), Some(lowest)) => (top, lowest),
+        _ => return err!(InstinctFiError::NoVotes),
+    };
+    let majority = counts[top].checked_mul(2).ok_or(InstinctFiError::Overflow)? > active;
+    Ok(if majority {
+        RoundOutcome::Decided(top as u8)
+    } else if counts[top] == counts[lowest] {
+        RoundOutcome::Decided(255)
+    } else {
+        RoundOutcome::Eliminate(lowest as u8)
+    })
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct TallyRanked<'info> {
+    /// Anyone may crank; pays the RankedTally rent on the first call
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + RankedTally::INIT_SPACE,
+        seeds = [b"ranked_tally", poll_account.key().as_ref()],
+        bump,
+    )]
+    pub ranked_tally: Account<'info, RankedTally>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a full count the way the crank does, round by round: returns
+    /// the winner and each round's counts.
+    fn instant_runoff(num_options: usize, ballots: &[&[u8]]) -> (u8, Vec<Vec<u64>>) {
+        let mut tally = RankedTally {
+            poll: Pubkey::default(),
+            round: 1,
+            eliminated: 0,
+            counts: Vec::new(),
+            exhausted: 0,
+            ballots_counted: 0,
+            round_counts: Vec::new(),
+            round_eliminated: Vec::new(),
+            complete: false,
+            bump: 0,
+        };
+        loop {
+            let mut counts = vec![0u64; num_options];
+            for ranking in ballots {
+                if let Some(option) = tally.top_choice(ranking) {
+                    counts[option as usize] += 1;
+                }
+            }
+            tally.round_counts.push(counts.clone());
+            match decide_round(&counts, tally.eliminated).unwrap() {
+                RoundOutcome::Decided(winner) => return (winner, tally.round_counts),
+                RoundOutcome::Eliminate(option) => tally.eliminated |= 1 << option,
+            }
+        }
+    }
+
+    #[test]
+    fn eliminated_ballots_move_to_the_next_preference() {
+        let ballots: [&[u8]; 8] = [&[0, 2], &[0, 2], &[0], &[1, 2], &[1, 2], &[2, 1], &[2, 1], &[2, 1]];
+        let (winner, rounds) = instant_runoff(3, &ballots);
+        assert_eq!(rounds, vec![vec![3, 2, 3], vec![3, 0, 5]]);
+        assert_eq!(winner, 2);
+    }
+
+    #[test]
+    fn several_elimination_rounds() {
+        // Both eliminations break last-place ties (3 before 1, then 2 before
+        // 1); 2's ballots exhaust and 0 wins 4 of the 7 left
+        let ballots: [&[u8]; 10] = [
+            &[0], &[0], &[0],
+            &[1, 3, 0], &[1, 3, 0],
+            &[2], &[2], &[2],
+            &[3, 1, 0], &[3, 0],
+        ];
+        let (winner, rounds) = instant_runoff(4, &ballots);
+        assert_eq!(
+            rounds,
+            vec![vec![3, 2, 3, 2], vec![4, 3, 3, 0], vec![4, 3, 0, 0]]
+        );
+        assert_eq!(winner, 0);
+    }
+
+    #[test]
+    fn last_place_ties_eliminate_the_highest_index() {
+        assert_eq!(decide_round(&[3, 2, 1, 1], 0).unwrap(), RoundOutcome::Eliminate(3));
+        // 2 is last outright, and its ballot moves to 1, which then wins
+        let ballots: [&[u8]; 5] = [&[0], &[0], &[1], &[2, 1], &[1]];
+        let (winner, rounds) = instant_runoff(3, &ballots);
+        assert_eq!(rounds[0], vec![2, 2, 1]);
+        assert_eq!(rounds[1], vec![2, 3, 0]);
+        assert_eq!(winner, 1);
+    }
+
+    #[test]
+    fn full_ties_and_empty_counts_are_255() {
+        assert_eq!(decide_round(&[2, 2], 0).unwrap(), RoundOutcome::Decided(255));
+        assert_eq!(decide_round(&[0, 0, 0], 0).unwrap(), RoundOutcome::Decided(255));
+        // Eliminated options don't count towards the tie
+        assert_eq!(decide_round(&[2, 0, 2], 0b010).unwrap(), RoundOutcome::Decided(255));
+        // A tie reached after eliminations
+        let ballots: [&[u8]; 5] = [&[0], &[0], &[1], &[1], &[2]];
+        let (winner, rounds) = instant_runoff(3, &ballots);
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(winner, 255);
+    }
+
+    #[test]
+    fn majority_is_of_non_exhausted_ballots() {
+        // 1's two ballots exhaust when it is eliminated; 2 then wins with 4 of
+        // the 7 ballots left, though not a majority of all 9
+        let ballots: [&[u8]; 9] = [&[0], &[0], &[0], &[1], &[1], &[2], &[2], &[2, 0], &[2, 1]];
+        let (winner, rounds) = instant_runoff(3, &ballots);
+        assert_eq!(rounds, vec![vec![3, 2, 4], vec![3, 0, 4]]);
+        assert_eq!(winner, 2);
+        // Half is not a majority
+        assert_eq!(decide_round(&[2, 1, 1], 0).unwrap(), RoundOutcome::Eliminate(2));
+        assert_eq!(decide_round(&[3, 2], 0).unwrap(), RoundOutcome::Decided(0));
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{AllowlistPass, PollAccount};
+use crate::errors::InstinctFiError;
+use crate::allowlist;
+
+/// Prove `voter` is on the poll's allowlist with cap `max_coins` and record
+/// an AllowlistPass the vote instructions check. Membership is public data,
+/// so anyone may submit the proof and pay the rent — typically the voter in
+/// the same transaction as their first `cast_vote`, or a relayer. Re-run it
+/// if the root changes.
+pub fn handler(
+    ctx: Context<VerifyAllowlist>,
+    _poll_id: u64,
+    max_coins: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let poll = &ctx.accounts.poll_account;
+    let voter_key = ctx.accounts.voter.key();
+
+    require!(
+        poll.allowlist_root != allowlist::OPEN_POLL_ROOT,
+        InstinctFiError::NotAllowlisted
+    );
+    require!(
+        allowlist::verify(&poll.allowlist_root, allowlist::leaf(&voter_key, max_coins), &proof),
+        InstinctFiError::InvalidAllowlistProof
+    );
+
+    let pass = &mut ctx.accounts.allowlist_pass;
+    pass.poll = poll.key();
+    pass.voter = voter_key;
+    pass.root = poll.allowlist_root;
+    pass.max_coins = max_coins;
+    pass.bump = ctx.bumps.allowlist_pass;
+
+    msg!(
+        "Allowlist: {} verified for poll {} (max {} coins)",
+        voter_key,
+        poll.poll_id,
+        max_coins
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct VerifyAllowlist<'info> {
+    /// Pays the pass rent (the voter or a relayer)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Voter being proven — only its key is used
+    pub voter: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistPass::INIT_SPACE,
+        seeds = [b"allowlist", poll_account.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub allowlist_pass: Account<'info, AllowlistPass>,
+
+    pub system_program: Program<'info, System>,
+}
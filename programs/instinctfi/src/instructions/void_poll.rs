@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+use crate::vault::{self, TokenLeg};
+
+/// Void a poll that missed its liquidity thresholds (permissionless crank).
+///
+/// Allowed once end_time has passed while `total_pool < min_total_pool` or
+/// `total_voters < min_voters`. The poll is marked VOIDED and the creator's
+/// investment is returned (less any referral rewards already paid out of the
+/// platform fee, and less seeded coins, which the creator's position reclaims
+/// like any voter). Voters then reclaim their stake with `refund_voided_poll`.
+pub fn handler(ctx: Context<VoidPoll>, _poll_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let poll = &ctx.accounts.poll_account;
+
+    // ── Guards ──
+    require!(poll.is_active(), InstinctFiError::AlreadySettled);
+    require!(!poll.is_civic(), InstinctFiError::NotAMarketPoll);
+    require!(poll.is_ended(&clock), InstinctFiError::PollNotEnded);
+    require!(
+        !poll.meets_liquidity_thresholds(),
+        InstinctFiError::LiquidityThresholdsMet
+    );
+
+    let token = vault::token_leg(
+        poll,
+        &ctx.accounts.collateral_mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    void_poll(
+        &mut ctx.accounts.poll_account,
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        token.as_ref(),
+    )
+}
+
+/// Refunds the creator and marks the poll VOIDED. Shared by `void_poll` and
+/// the settle instructions, which void a thin poll instead of settling it.
+pub(crate) fn void_poll<'info>(
+    poll: &mut Account<'info, PollAccount>,
+    treasury: AccountInfo<'info>,
+    creator: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    token: Option<&TokenLeg<'info>>,
+) -> Result<()> {
+    // ── Creator refund: investment minus referral rewards already paid ──
+    // Seeded coins are refunded separately through the creator's position
+    // (`refund_voided_poll`), so they are left out here.
+    let referral_paid = poll.initial_platform_fee().saturating_sub(poll.platform_fee);
+    let creator_refund = poll.creator_investment
+        .saturating_sub(referral_paid)
+        .saturating_sub(poll.creator_seeded);
+
+    let available = vault::available(&treasury, token, poll.set_collateral)?;
+    require!(available >= creator_refund, InstinctFiError::TreasuryInsufficient);
+
+    vault::withdraw(
+        &poll.key(),
+        poll.treasury_bump,
+        treasury,
+        creator,
+        system_program,
+        token,
+        creator_refund,
+    )?;
+
+    // ── Mark voided ──
+    poll.status = PollAccount::STATUS_VOIDED;
+    poll.winning_option = 255;
+
+    msg!(
+        "Poll {} voided (pool={}, voters={}). Creator refunded {} lamports",
+        poll.poll_id,
+        poll.total_pool,
+        poll.total_voters,
+        creator_refund
+    );
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(poll_id: u64)]
+pub struct VoidPoll<'info> {
+    /// Anyone can void a thin poll (permissionless crank)
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// CHECK: Poll creator — receives the investment refund. Validated by constraint.
+    #[account(
+        mut,
+        constraint = creator.key() == poll_account.creator @ InstinctFiError::UnauthorizedNotCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// The poll to void
+    #[account(
+        mut,
+        seeds = [b"poll", poll_account.creator.as_ref(), &poll_id.to_le_bytes()],
+        bump = poll_account.bump,
+    )]
+    pub poll_account: Account<'info, PollAccount>,
+
+    /// CHECK: Treasury PDA — source of the creator refund
+    #[account(
+        mut,
+        seeds = [b"treasury", poll_account.key().as_ref()],
+        bump = poll_account.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// SPL polls only: the poll's collateral mint (omit for SOL polls)
+    #[account(address = poll_account.collateral_mint @ InstinctFiError::CollateralMismatch)]
+    pub collateral_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// SPL polls only: collateral vault — the treasury PDA's ATA
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL polls only: creator's token account receiving the refund
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = creator,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::ReferralAccount;
+use crate::errors::InstinctFiError;
+
+/// Withdraw all accrued referral rewards to the referrer's wallet.
+///
+/// Rewards are lamports held on the ReferralAccount above its rent-exempt
+/// minimum; the program owns the account, so it debits it directly.
+pub fn handler(ctx: Context<WithdrawReferralRewards>) -> Result<()> {
+    let referral_info = ctx.accounts.referral_account.to_account_info();
+    let rent_exempt_min = Rent::get()?.minimum_balance(referral_info.data_len());
+    let amount = referral_info.lamports().saturating_sub(rent_exempt_min);
+    require!(amount > 0, InstinctFiError::NothingToWithdraw);
+
+    **referral_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let referral = &mut ctx.accounts.referral_account;
+    referral.total_withdrawn = referral.total_withdrawn
+        .checked_add(amount)
+        .ok_or(InstinctFiError::Overflow)?;
+
+    msg!("Referrer {} withdrew {} lamports", referral.referrer, amount);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct WithdrawReferralRewards<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump = referral_account.bump,
+        has_one = referrer @ InstinctFiError::Unauthorized,
+    )]
+    pub referral_account: Account<'info, ReferralAccount>,
+}
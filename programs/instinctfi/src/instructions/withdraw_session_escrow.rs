@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::SessionEscrow;
+use crate::errors::InstinctFiError;
+
+/// Withdraw the whole escrowed balance (above rent) back to the authority.
+///
+/// The program owns the escrow, so it debits it directly. Outstanding
+/// sessions stay valid but cannot vote until the escrow is topped up again.
+pub fn handler(ctx: Context<WithdrawSessionEscrow>) -> Result<()> {
+    let escrow_info = ctx.accounts.session_escrow.to_account_info();
+    let rent_exempt_min = Rent::get()?.minimum_balance(escrow_info.data_len());
+    let amount = escrow_info.lamports().saturating_sub(rent_exempt_min);
+    require!(amount > 0, InstinctFiError::NothingToWithdraw);
+
+    **escrow_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    msg!("Session escrow: {} withdrew {} lamports", ctx.accounts.authority.key(), amount);
+    Ok(())
+}
+
+// ─── Accounts ────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct WithdrawSessionEscrow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"session_escrow", authority.key().as_ref()],
+        bump = session_escrow.bump,
+        has_one = authority @ InstinctFiError::Unauthorized,
+    )]
+    pub session_escrow: Account<'info, SessionEscrow>,
+}
@@ -3,8 +3,19 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod errors;
 pub mod instructions;
+pub mod pricing;
+pub mod vault;
+pub mod receipt;
+pub mod referral;
+pub mod relay;
+pub mod allowlist;
+pub mod token_gate;
+pub mod attestation;
+pub mod events;
 
 use instructions::*;
+use relay::RelayIntent;
+use state::PriceCurve;
 
 // Deployed to Solana devnet on 2026-03-01
 declare_id!("J9AqrLZWDXaQfDwtFpC2GG9hBb7SAPxRwVpGs753EgWV");
@@ -59,13 +70,25 @@ pub mod instinctfi {
     }
 
     /// Buy option-coins by sending real SOL to the treasury.
+    /// Fails if the bonding-curve cost exceeds `max_total_cost`.
     pub fn cast_vote(
         ctx: Context<CastVote>,
         poll_id: u64,
         option_index: u8,
         num_coins: u64,
+        max_total_cost: u64,
     ) -> Result<()> {
-        instructions::cast_vote::handler(ctx, poll_id, option_index, num_coins)
+        instructions::cast_vote::handler(ctx, poll_id, option_index, num_coins, max_total_cost)
+    }
+
+    /// Buy coins on several options at once — one combined SOL transfer.
+    pub fn cast_votes(
+        ctx: Context<CastVotes>,
+        poll_id: u64,
+        entries: Vec<VoteEntry>,
+        max_total_cost: u64,
+    ) -> Result<()> {
+        instructions::cast_votes::handler(ctx, poll_id, entries, max_total_cost)
     }
 
     /// Settle a poll after end time. Anyone can call (permissionless).
@@ -114,4 +137,344 @@ pub mod instinctfi {
             ctx, poll_id, title, description, category, image_url, options, end_time,
         )
     }
+
+    /// Create the outcome-token mint for one poll option (permissionless).
+    pub fn init_outcome_mint(
+        ctx: Context<InitOutcomeMint>,
+        poll_id: u64,
+        option_index: u8,
+    ) -> Result<()> {
+        instructions::init_outcome_mint::handler(ctx, poll_id, option_index)
+    }
+
+    /// Deposit `num_sets × unit_price` and receive `num_sets` of every option's token.
+    pub fn mint_complete_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintCompleteSet<'info>>,
+        poll_id: u64,
+        num_sets: u64,
+    ) -> Result<()> {
+        instructions::mint_complete_set::handler(ctx, poll_id, num_sets)
+    }
+
+    /// Burn one of every option's token per set for the full deposit back.
+    pub fn redeem_complete_set<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemCompleteSet<'info>>,
+        poll_id: u64,
+        num_sets: u64,
+    ) -> Result<()> {
+        instructions::redeem_complete_set::handler(ctx, poll_id, num_sets)
+    }
+
+    /// After settlement, burn winning outcome tokens for `unit_price` each.
+    pub fn redeem_winning_tokens(ctx: Context<RedeemWinningTokens>, poll_id: u64, amount: u64) -> Result<()> {
+        instructions::redeem_winning_tokens::handler(ctx, poll_id, amount)
+    }
+
+    /// Create the order book and escrow for a poll (permissionless).
+    pub fn init_order_book(ctx: Context<InitOrderBook>, poll_id: u64) -> Result<()> {
+        instructions::init_order_book::handler(ctx, poll_id)
+    }
+
+    /// Place a bid (escrows SOL) or ask (escrows coins) on an option.
+    pub fn place_order(
+        ctx: Context<PlaceOrder>,
+        poll_id: u64,
+        side: u8,
+        option_index: u8,
+        price: u64,
+        quantity: u64,
+    ) -> Result<()> {
+        instructions::place_order::handler(ctx, poll_id, side, option_index, price, quantity)
+    }
+
+    /// Close an order: refund unspent escrow, collect proceeds and fills.
+    pub fn cancel_order(ctx: Context<CancelOrder>, poll_id: u64, order_id: u64) -> Result<()> {
+        instructions::cancel_order::handler(ctx, poll_id, order_id)
+    }
+
+    /// Match crossing orders by price-time priority. Anyone can call.
+    pub fn match_orders<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MatchOrders<'info>>,
+        poll_id: u64,
+        max_fills: u8,
+    ) -> Result<()> {
+        instructions::match_orders::handler(ctx, poll_id, max_fills)
+    }
+
+    /// Set a linear/exponential bonding curve per option (creator only, 0 votes).
+    pub fn set_price_curves(
+        ctx: Context<SetPriceCurves>,
+        poll_id: u64,
+        curves: Vec<PriceCurve>,
+    ) -> Result<()> {
+        instructions::set_price_curves::handler(ctx, poll_id, curves)
+    }
+
+    /// Burn a participation receipt and close the voter's settled vote record.
+    pub fn burn_receipt(ctx: Context<BurnReceipt>, poll_id: u64) -> Result<()> {
+        instructions::burn_receipt::handler(ctx, poll_id)
+    }
+
+    /// Set the referral share of the platform fee (PLATFORM_ADMIN only).
+    pub fn set_platform_config(ctx: Context<SetPlatformConfig>, referral_share_bps: u16) -> Result<()> {
+        instructions::set_platform_config::handler(ctx, referral_share_bps)
+    }
+
+    /// Register as a referrer.
+    pub fn init_referral_account(ctx: Context<InitReferralAccount>) -> Result<()> {
+        instructions::init_referral_account::handler(ctx)
+    }
+
+    /// Withdraw accrued referral rewards.
+    pub fn withdraw_referral_rewards(ctx: Context<WithdrawReferralRewards>) -> Result<()> {
+        instructions::withdraw_referral_rewards::handler(ctx)
+    }
+
+    /// Set per-voter stake and per-option coin caps (creator only, 0 votes).
+    pub fn set_stake_caps(
+        ctx: Context<SetStakeCaps>,
+        poll_id: u64,
+        max_stake_per_voter: u64,
+        max_coins_per_option: u64,
+    ) -> Result<()> {
+        instructions::set_stake_caps::handler(ctx, poll_id, max_stake_per_voter, max_coins_per_option)
+    }
+
+    /// Set the minimum pool / voters a poll needs to settle (creator only, 0 votes).
+    pub fn set_liquidity_thresholds(
+        ctx: Context<SetLiquidityThresholds>,
+        poll_id: u64,
+        min_total_pool: u64,
+        min_voters: u32,
+    ) -> Result<()> {
+        instructions::set_liquidity_thresholds::handler(ctx, poll_id, min_total_pool, min_voters)
+    }
+
+    /// Void an ended poll that missed its liquidity thresholds; refunds the creator.
+    pub fn void_poll(ctx: Context<VoidPoll>, poll_id: u64) -> Result<()> {
+        instructions::void_poll::handler(ctx, poll_id)
+    }
+
+    /// Voter reclaims their full stake from a voided poll.
+    pub fn refund_voided_poll(ctx: Context<RefundVoidedPoll>, poll_id: u64) -> Result<()> {
+        instructions::refund_voided_poll::handler(ctx, poll_id)
+    }
+
+    /// Seed initial odds from the creator's pool seed (creator only, 0 votes).
+    pub fn seed_odds(ctx: Context<SeedOdds>, poll_id: u64, coins: Vec<u64>) -> Result<()> {
+        instructions::seed_odds::handler(ctx, poll_id, coins)
+    }
+
+    /// Sponsor a poll's pool without taking a position.
+    pub fn sponsor_poll(
+        ctx: Context<SponsorPoll>,
+        poll_id: u64,
+        amount: u64,
+        memo: String,
+    ) -> Result<()> {
+        instructions::sponsor_poll::handler(ctx, poll_id, amount, memo)
+    }
+
+    /// Refund a sponsorship once the poll is voided, deleted or settled without votes.
+    pub fn refund_sponsor(ctx: Context<RefundSponsor>, poll_id: u64) -> Result<()> {
+        instructions::refund_sponsor::handler(ctx, poll_id)
+    }
+
+    /// Authorize an ephemeral session key to vote and claim on the signer's behalf.
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        expires_at: i64,
+        spend_cap: u64,
+        scope_poll: Pubkey,
+        scope_category: String,
+    ) -> Result<()> {
+        instructions::create_session::handler(ctx, session_key, expires_at, spend_cap, scope_poll, scope_category)
+    }
+
+    /// Revoke a session key and reclaim its rent.
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session::handler(ctx)
+    }
+
+    /// Top up the signer's session escrow (created on first deposit).
+    pub fn deposit_session_escrow(ctx: Context<DepositSessionEscrow>, amount: u64) -> Result<()> {
+        instructions::deposit_session_escrow::handler(ctx, amount)
+    }
+
+    /// Withdraw the whole session escrow balance back to the wallet.
+    pub fn withdraw_session_escrow(ctx: Context<WithdrawSessionEscrow>) -> Result<()> {
+        instructions::withdraw_session_escrow::handler(ctx)
+    }
+
+    /// Vote signed by a session key; paid from the authority's session escrow.
+    pub fn session_cast_vote(
+        ctx: Context<SessionCastVote>,
+        poll_id: u64,
+        option_index: u8,
+        num_coins: u64,
+        max_total_cost: u64,
+    ) -> Result<()> {
+        instructions::session_cast_vote::handler(ctx, poll_id, option_index, num_coins, max_total_cost)
+    }
+
+    /// Claim winnings signed by a session key; paid to the authority's wallet.
+    pub fn session_claim_reward(ctx: Context<SessionClaimReward>, poll_id: u64) -> Result<()> {
+        instructions::session_claim_reward::handler(ctx, poll_id)
+    }
+
+    /// Submit a voter-signed vote intent; the relayer pays fees, the voter's escrow pays stake.
+    pub fn relay_cast_vote(ctx: Context<RelayCastVote>, poll_id: u64, intent: RelayIntent) -> Result<()> {
+        instructions::relay_cast_vote::handler(ctx, poll_id, intent)
+    }
+
+    /// Restrict a poll to a merkle allowlist (creator or admin, before the first vote).
+    pub fn set_allowlist_root(ctx: Context<SetAllowlistRoot>, poll_id: u64, root: [u8; 32]) -> Result<()> {
+        instructions::set_allowlist_root::handler(ctx, poll_id, root)
+    }
+
+    /// Prove a voter's allowlist membership and record their AllowlistPass.
+    pub fn verify_allowlist(
+        ctx: Context<VerifyAllowlist>,
+        poll_id: u64,
+        max_coins: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::verify_allowlist::handler(ctx, poll_id, max_coins, proof)
+    }
+
+    /// Restrict voting to holders of an SPL token (creator only, 0 votes).
+    pub fn set_token_gate(
+        ctx: Context<SetTokenGate>,
+        poll_id: u64,
+        gate_min_amount: u64,
+    ) -> Result<()> {
+        instructions::set_token_gate::handler(ctx, poll_id, gate_min_amount)
+    }
+
+    /// Create a free civic poll (single-choice, ranked or approval) gated by a merkle
+    /// allowlist and/or issuer attestations.
+    pub fn create_civic_poll(
+        ctx: Context<CreateCivicPoll>,
+        poll_id: u64,
+        params: CivicPollParams,
+    ) -> Result<()> {
+        instructions::create_civic_poll::handler(ctx, poll_id, params)
+    }
+
+    /// Cast an eligible identity's single free vote on a civic poll.
+    pub fn cast_civic_vote(ctx: Context<CastCivicVote>, poll_id: u64, option_index: u8) -> Result<()> {
+        instructions::cast_civic_vote::handler(ctx, poll_id, option_index)
+    }
+
+    /// Record the final tally of an ended civic poll (permissionless).
+    pub fn finalize_civic_poll(ctx: Context<FinalizeCivicPoll>, poll_id: u64) -> Result<()> {
+        instructions::finalize_civic_poll::handler(ctx, poll_id)
+    }
+
+    /// Cast an eligible identity's single ranked ballot on a ranked-choice poll.
+    pub fn cast_ranked_vote(ctx: Context<CastRankedVote>, poll_id: u64, ranking: Vec<u8>) -> Result<()> {
+        instructions::cast_ranked_vote::handler(ctx, poll_id, ranking)
+    }
+
+    /// Resumable instant-runoff crank over ranked ballots (permissionless).
+    pub fn tally_ranked(ctx: Context<TallyRanked>, poll_id: u64) -> Result<()> {
+        instructions::tally_ranked::handler(ctx, poll_id)
+    }
+
+    /// Cast an eligible identity's single approval ballot (bitmask of options).
+    pub fn cast_approval_vote(ctx: Context<CastApprovalVote>, poll_id: u64, approvals: u8) -> Result<()> {
+        instructions::cast_approval_vote::handler(ctx, poll_id, approvals)
+    }
+
+    /// Whitelist an identity-attestation issuer (PLATFORM_ADMIN only).
+    pub fn add_attestation_issuer(ctx: Context<AddAttestationIssuer>, issuer: Pubkey) -> Result<()> {
+        instructions::add_attestation_issuer::handler(ctx, issuer)
+    }
+
+    /// Remove an attestation issuer; its attestations stop being accepted.
+    pub fn remove_attestation_issuer(ctx: Context<RemoveAttestationIssuer>) -> Result<()> {
+        instructions::remove_attestation_issuer::handler(ctx)
+    }
+
+    /// Issue (or renew) a wallet's identity attestation (whitelisted issuer only).
+    pub fn issue_attestation(
+        ctx: Context<IssueAttestation>,
+        identity_hash: [u8; 32],
+        region: u16,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::issue_attestation::handler(ctx, identity_hash, region, expires_at)
+    }
+
+    /// Revoke an attestation (its issuer or PLATFORM_ADMIN).
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        instructions::revoke_attestation::handler(ctx)
+    }
+
+    /// Open the per-region tally of an attestation-gated civic poll (permissionless).
+    pub fn init_region_tally(ctx: Context<InitRegionTally>, poll_id: u64, region: u16) -> Result<()> {
+        instructions::init_region_tally::handler(ctx, poll_id, region)
+    }
+
+    /// Emit a finished civic poll's regional results as events (read-only).
+    pub fn emit_regional_results<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EmitRegionalResults<'info>>,
+        poll_id: u64,
+    ) -> Result<()> {
+        instructions::emit_regional_results::handler(ctx, poll_id)
+    }
+
+    /// Create a free multi-question civic survey (one end time and response per voter, no treasury).
+    pub fn create_survey(
+        ctx: Context<CreateSurvey>,
+        survey_id: u64,
+        title: String,
+        description: String,
+        end_time: i64,
+        require_attestation: bool,
+    ) -> Result<()> {
+        instructions::create_survey::handler(ctx, survey_id, title, description, end_time, require_attestation)
+    }
+
+    /// Append a question to a survey (creator only, before the first response).
+    pub fn add_survey_question(
+        ctx: Context<AddSurveyQuestion>,
+        survey_id: u64,
+        prompt: String,
+        options: Vec<String>,
+    ) -> Result<()> {
+        instructions::add_survey_question::handler(ctx, survey_id, prompt, options)
+    }
+
+    /// Answer every question of a survey in one transaction.
+    pub fn answer_survey<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AnswerSurvey<'info>>,
+        survey_id: u64,
+        answers: Vec<u8>,
+    ) -> Result<()> {
+        instructions::answer_survey::handler(ctx, survey_id, answers)
+    }
+
+    /// Schedule when voting opens (creator only, zero votes).
+    pub fn set_start_time(ctx: Context<SetStartTime>, poll_id: u64, start_time: i64) -> Result<()> {
+        instructions::set_start_time::handler(ctx, poll_id, start_time)
+    }
+
+    /// Set the anti-sniping soft-close rule (creator only, zero votes).
+    pub fn set_soft_close(
+        ctx: Context<SetSoftClose>,
+        poll_id: u64,
+        window: u32,
+        threshold_bps: u16,
+        extension: u32,
+        max_extensions: u8,
+    ) -> Result<()> {
+        instructions::set_soft_close::handler(ctx, poll_id, window, threshold_bps, extension, max_extensions)
+    }
+
+    /// Grow a PollAccount or VoteAccount from the original layout to the current one (permissionless).
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::handler(ctx)
+    }
 }
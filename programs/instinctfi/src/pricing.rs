@@ -0,0 +1,273 @@
+use anchor_lang::prelude::*;
+use crate::state::{PollAccount, PriceCurve, VoteAccount};
+use crate::errors::InstinctFiError;
+
+/// Fixed-point scale for exponential curve growth factors (1e12).
+const SCALE: u128 = 1_000_000_000_000;
+/// Basis-point denominator.
+const BPS: u128 = 10_000;
+
+/// Exact price of a `cast_vote`, as the instruction will charge it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VoteQuote {
+    /// Lamports debited from the voter — pass as (or below) `max_total_cost`
+    pub total_cost: u64,
+    /// Price of the next coin on this option after the vote lands
+    pub next_coin_price: u64,
+}
+
+/// Quote a `cast_vote` against the poll's current state.
+///
+/// `held` is the coins the voter already holds on the option (see
+/// `held_coins`); only the quadratic curve depends on it.
+///
+/// Clients should fetch the poll, quote, show `total_cost` to the user and
+/// sign it as `max_total_cost`; if the poll moves before the transaction
+/// lands, the instruction fails with `SlippageExceeded` instead of overcharging.
+pub fn quote_cast_vote(
+    poll: &PollAccount,
+    option_index: u8,
+    num_coins: u64,
+    held: u64,
+) -> Result<VoteQuote> {
+    let idx = option_index as usize;
+    require!(idx < poll.options.len(), InstinctFiError::InvalidOption);
+    require!(num_coins > 0, InstinctFiError::ZeroCoins);
+
+    let sold = poll.vote_counts[idx];
+    let curve = option_curve(poll, idx);
+    Ok(VoteQuote {
+        total_cost: curve_cost(poll.unit_price, curve, sold, held, num_coins)?,
+        next_coin_price: curve_cost(
+            poll.unit_price,
+            curve,
+            sold.checked_add(num_coins).ok_or(InstinctFiError::Overflow)?,
+            held.checked_add(num_coins).ok_or(InstinctFiError::Overflow)?,
+            1,
+        )?,
+    })
+}
+
+/// Coins a voter already holds on `option_index` (0 for a fresh VoteAccount).
+pub fn held_coins(vote: &VoteAccount, option_index: u8) -> u64 {
+    vote.votes_per_option
+        .get(option_index as usize)
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Quote the lamports returned for redeeming `num` complete sets or winning
+/// outcome tokens. Each is backed by the `unit_price` it was minted for, so
+/// the quote never moves.
+pub fn quote_redeem(poll: &PollAccount, num: u64) -> Result<u64> {
+    Ok(num.checked_mul(poll.unit_price).ok_or(InstinctFiError::Overflow)?)
+}
+
+/// Lamport cost of buying `num_coins` coins on `option_index`, given the
+/// option's bonding curve, its current `vote_counts` (coins already sold)
+/// and the `held` coins of the buying voter.
+pub fn vote_cost(poll: &PollAccount, option_index: usize, held: u64, num_coins: u64) -> Result<u64> {
+    curve_cost(
+        poll.unit_price,
+        option_curve(poll, option_index),
+        poll.vote_counts[option_index],
+        held,
+        num_coins,
+    )
+}
+
+/// The option's curve (flat when the poll predates per-option curves).
+fn option_curve(poll: &PollAccount, option_index: usize) -> PriceCurve {
+    poll.price_curves
+        .get(option_index)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Cost of `num_coins` coins on an option priced by `curve` once `sold`
+/// coins have been sold, to a voter already holding `held` of them.
+///
+/// Coin number `k` (0-based, counted across all voters) is priced at:
+///   Flat:        unit_price
+///   Linear:      unit_price + param × k                 (param = lamports/coin)
+///   Exponential: unit_price × (1 + param / 10_000)^k    (param = bps/coin)
+///
+/// Quadratic is per voter instead: holding `h` coins costs `h² × unit_price`
+/// in total, so coin number `j` of the voter's own (0-based) costs
+/// `(2j + 1) × unit_price`.
+///
+/// The sum over `k = sold .. sold + num_coins` is computed in closed form with
+/// checked u128 math. Exponential costs are rounded up — the upper power
+/// up, the lower one down, the division up — so the treasury is never
+/// undercharged.
+fn curve_cost(
+    unit_price: u64,
+    curve: PriceCurve,
+    sold: u64,
+    held: u64,
+    num_coins: u64,
+) -> Result<u64> {
+    if num_coins == 0 {
+        return Ok(0);
+    }
+    let unit_price = unit_price as u128;
+    let sold = sold as u128;
+    let n = num_coins as u128;
+
+    let base = n.checked_mul(unit_price).ok_or(InstinctFiError::Overflow)?;
+
+    let cost = match curve.kind {
+        PriceCurve::KIND_FLAT => base,
+        PriceCurve::KIND_LINEAR => {
+            // Σ k for k in sold..sold+n = n × (2·sold + n − 1) / 2
+            let index_sum = sold
+                .checked_mul(2)
+                .and_then(|v| v.checked_add(n))
+                .and_then(|v| v.checked_sub(1))
+                .and_then(|v| v.checked_mul(n))
+                .ok_or(InstinctFiError::Overflow)?
+                / 2;
+            let premium = index_sum
+                .checked_mul(curve.param as u128)
+                .ok_or(InstinctFiError::Overflow)?;
+            base.checked_add(premium).ok_or(InstinctFiError::Overflow)?
+        }
+        PriceCurve::KIND_EXPONENTIAL => {
+            require!(curve.param > 0, InstinctFiError::InvalidPriceCurve);
+            // Σ g^k for k in sold..sold+n = (g^(sold+n) − g^sold) / (g − 1)
+            let growth = SCALE + SCALE * curve.param as u128 / BPS;
+            let from = pow_scaled(growth, sold, false)?;
+            let to = pow_scaled(growth, sold.checked_add(n).ok_or(InstinctFiError::Overflow)?, true)?;
+            let numerator = (to - from)
+                .checked_mul(unit_price)
+                .and_then(|v| v.checked_mul(BPS))
+                .ok_or(InstinctFiError::Overflow)?;
+            let denominator = (curve.param as u128)
+                .checked_mul(SCALE)
+                .ok_or(InstinctFiError::Overflow)?;
+            numerator
+                .checked_add(denominator - 1)
+                .ok_or(InstinctFiError::Overflow)?
+                / denominator
+        }
+        PriceCurve::KIND_QUADRATIC => {
+            // (held + n)² − held² = n × (2·held + n)
+            let units = (held as u128)
+                .checked_mul(2)
+                .and_then(|v| v.checked_add(n))
+                .and_then(|v| v.checked_mul(n))
+                .ok_or(InstinctFiError::Overflow)?;
+            units.checked_mul(unit_price).ok_or(InstinctFiError::Overflow)?
+        }
+        _ => return err!(InstinctFiError::InvalidPriceCurve),
+    };
+
+    u64::try_from(cost).map_err(|_| error!(InstinctFiError::Overflow))
+}
+
+/// `base^exp` for a `SCALE`-fixed-point `base`, by repeated squaring.
+/// Every intermediate product is rounded the same way, so the result is a
+/// bound on the exact power: above it when `round_up`, below it otherwise.
+fn pow_scaled(mut base: u128, mut exp: u128, round_up: bool) -> Result<u128> {
+    let mul = |x: u128, y: u128| -> Result<u128> {
+        let product = x.checked_mul(y).ok_or(InstinctFiError::Overflow)?;
+        Ok(if round_up { product.div_ceil(SCALE) } else { product / SCALE })
+    };
+    let mut acc = SCALE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = mul(acc, base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = mul(base, base)?;
+        }
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNIT_PRICE: u64 = 10_000_000;
+
+    fn curve(kind: u8, param: u64) -> PriceCurve {
+        PriceCurve { kind, param }
+    }
+
+    /// Per-coin reference: coin `k` overall, coin `j` of the voter's own.
+    fn naive_cost(curve: PriceCurve, sold: u64, held: u64, num_coins: u64) -> u128 {
+        (0..num_coins)
+            .map(|i| {
+                let (k, j) = ((sold + i) as u128, (held + i) as u128);
+                let unit = UNIT_PRICE as u128;
+                match curve.kind {
+                    PriceCurve::KIND_FLAT => unit,
+                    PriceCurve::KIND_LINEAR => unit + curve.param as u128 * k,
+                    PriceCurve::KIND_QUADRATIC => (2 * j + 1) * unit,
+                    _ => unreachable!(),
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn flat_linear_and_quadratic_match_per_coin_sum() {
+        let curves = [
+            curve(PriceCurve::KIND_FLAT, 0),
+            curve(PriceCurve::KIND_LINEAR, 1),
+            curve(PriceCurve::KIND_LINEAR, 250_000),
+            curve(PriceCurve::KIND_QUADRATIC, 0),
+        ];
+        for c in curves {
+            for (sold, held, n) in [(0, 0, 1), (0, 0, 7), (13, 2, 1), (13, 2, 40), (999, 31, 64)] {
+                let cost = curve_cost(UNIT_PRICE, c, sold, held, n).unwrap();
+                assert_eq!(cost as u128, naive_cost(c, sold, held, n), "kind {} sold {sold} held {held} n {n}", c.kind);
+            }
+        }
+    }
+
+    #[test]
+    fn quadratic_depends_on_held_not_sold() {
+        let c = curve(PriceCurve::KIND_QUADRATIC, 0);
+        // n coins from scratch cost n² units, whatever others bought
+        assert_eq!(curve_cost(UNIT_PRICE, c, 0, 0, 5).unwrap(), 25 * UNIT_PRICE);
+        assert_eq!(curve_cost(UNIT_PRICE, c, 500, 0, 5).unwrap(), 25 * UNIT_PRICE);
+        // topping 3 coins up to 5 costs 5² − 3² units
+        assert_eq!(curve_cost(UNIT_PRICE, c, 3, 3, 2).unwrap(), 16 * UNIT_PRICE);
+    }
+
+    #[test]
+    fn exponential_rounds_up_by_a_few_lamports() {
+        for bps in [1, 50, 1_000, PriceCurve::MAX_EXPONENTIAL_BPS] {
+            let c = curve(PriceCurve::KIND_EXPONENTIAL, bps);
+            let growth = 1.0 + bps as f64 / 10_000.0;
+            for (sold, n) in [(0, 1), (0, 10), (17, 1), (17, 10), (30, 5)] {
+                let exact: f64 = (sold..sold + n)
+                    .map(|k| UNIT_PRICE as f64 * growth.powi(k as i32))
+                    .sum();
+                let cost = curve_cost(UNIT_PRICE, c, sold, 0, n).unwrap() as f64;
+                // never undercharges (up to f64 error); fixed-point error is
+                // amplified by 1 / (growth − 1), so allow a few lamports over
+                assert!(cost >= exact * (1.0 - 1e-12), "bps {bps} sold {sold} n {n}: {cost} < {exact}");
+                assert!(cost <= exact * (1.0 + 1e-12) + 4.0, "bps {bps} sold {sold} n {n}: {cost} ≫ {exact}");
+            }
+        }
+    }
+
+    #[test]
+    fn pow_scaled_brackets_the_exact_power() {
+        let growth = SCALE + SCALE * 333 / BPS;
+        for exp in [0u128, 1, 2, 3, 10, 31, 64] {
+            let up = pow_scaled(growth, exp, true).unwrap();
+            let down = pow_scaled(growth, exp, false).unwrap();
+            let exact = SCALE as f64 * (1.0333f64).powi(exp as i32);
+            assert!(down <= up);
+            assert!(up as f64 >= exact * (1.0 - 1e-12), "exp {exp}");
+            assert!(down as f64 <= exact * (1.0 + 1e-12), "exp {exp}");
+        }
+        assert_eq!(pow_scaled(growth, 0, true).unwrap(), SCALE);
+        assert_eq!(pow_scaled(growth, 1, true).unwrap(), growth);
+    }
+}
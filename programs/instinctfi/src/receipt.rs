@@ -0,0 +1,225 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{self, Token2022};
+use anchor_spl::token_2022_extensions::{
+    metadata_pointer_initialize, mint_close_authority_initialize,
+    non_transferable_mint_initialize, token_metadata_initialize, token_metadata_update_field,
+    MetadataPointerInitialize, MintCloseAuthorityInitialize, NonTransferableMintInitialize,
+    TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+use crate::attestation::create_pda;
+
+/// Symbol carried by every participation receipt.
+pub const RECEIPT_SYMBOL: &str = "VOTE";
+
+/// Accounts needed to mint a participation receipt.
+///
+/// The receipt is a Token-2022 NFT: a non-transferable, 0-decimal mint at
+/// PDA `["receipt", poll, voter]` with supply 1, held in the voter's ATA.
+/// Its metadata (stored on the mint itself) carries the poll title as name
+/// and `poll` / `option` fields. The treasury PDA is metadata update
+/// authority and mint close authority, so `burn_receipt` can reclaim the rent.
+pub struct ReceiptAccounts<'info> {
+    pub voter: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub treasury: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Resolves the optional receipt accounts of `cast_vote`: `None` when the
+/// voter did not ask for a receipt, all four accounts required otherwise.
+pub fn receipt_accounts<'info>(
+    voter: &Signer<'info>,
+    treasury: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    mint: &Option<UncheckedAccount<'info>>,
+    token_account: &Option<UncheckedAccount<'info>>,
+    token_program: &Option<Program<'info, Token2022>>,
+    associated_token_program: &Option<Program<'info, AssociatedToken>>,
+) -> Result<Option<ReceiptAccounts<'info>>> {
+    match (mint, token_account, token_program, associated_token_program) {
+        (None, None, None, None) => Ok(None),
+        (Some(mint), Some(token_account), Some(token_program), Some(associated_token_program)) => {
+            Ok(Some(ReceiptAccounts {
+                voter: voter.to_account_info(),
+                mint: mint.to_account_info(),
+                token_account: token_account.to_account_info(),
+                treasury: treasury.to_account_info(),
+                token_program: token_program.to_account_info(),
+                associated_token_program: associated_token_program.to_account_info(),
+                system_program: system_program.to_account_info(),
+            }))
+        }
+        _ => err!(InstinctFiError::MissingReceiptAccounts),
+    }
+}
+
+/// Creates the receipt mint, writes its metadata and mints the single
+/// receipt token to the voter. Mint authority is revoked afterwards.
+pub fn mint_receipt(
+    accounts: &ReceiptAccounts,
+    poll: &PollAccount,
+    poll_key: &Pubkey,
+    option_index: u8,
+    mint_bump: u8,
+) -> Result<()> {
+    let voter_key = accounts.voter.key();
+    let treasury_key = accounts.treasury.key();
+    let mint_seeds: &[&[u8]] = &[b"receipt", poll_key.as_ref(), voter_key.as_ref(), &[mint_bump]];
+    let treasury_seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[poll.treasury_bump]];
+
+    let poll_field = poll_key.to_string();
+    let option_field = poll.options[option_index as usize].clone();
+    let metadata = TokenMetadata {
+        name: poll.title.clone(),
+        symbol: RECEIPT_SYMBOL.to_string(),
+        uri: String::new(),
+        additional_metadata: vec![
+            ("poll".to_string(), poll_field.clone()),
+            ("option".to_string(), option_field.clone()),
+        ],
+        ..Default::default()
+    };
+
+    // ── Create the mint with room for its fixed extensions; fund the
+    //    metadata up front since Token-2022 reallocs as it is written ──
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+        ExtensionType::NonTransferable,
+        ExtensionType::MetadataPointer,
+        ExtensionType::MintCloseAuthority,
+    ])?;
+    let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+    // (tolerates lamports sent to the mint address beforehand)
+    create_pda(
+        &accounts.voter,
+        &accounts.mint,
+        &accounts.system_program,
+        lamports,
+        space,
+        &token_2022::ID,
+        mint_seeds,
+    )?;
+
+    // ── Extensions, then the base mint ──
+    non_transferable_mint_initialize(CpiContext::new(
+        accounts.token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: accounts.token_program.clone(),
+            mint: accounts.mint.clone(),
+        },
+    ))?;
+    metadata_pointer_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(treasury_key),
+        Some(accounts.mint.key()),
+    )?;
+    mint_close_authority_initialize(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            MintCloseAuthorityInitialize {
+                token_program_id: accounts.token_program.clone(),
+                mint: accounts.mint.clone(),
+            },
+        ),
+        Some(&treasury_key),
+    )?;
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            accounts.token_program.clone(),
+            token_2022::InitializeMint2 {
+                mint: accounts.mint.clone(),
+            },
+        ),
+        0,
+        &treasury_key,
+        None,
+    )?;
+
+    // ── Metadata: name = poll title, plus poll / option fields ──
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            TokenMetadataInitialize {
+                token_program_id: accounts.token_program.clone(),
+                metadata: accounts.mint.clone(),
+                update_authority: accounts.treasury.clone(),
+                mint_authority: accounts.treasury.clone(),
+                mint: accounts.mint.clone(),
+            },
+            &[treasury_seeds],
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+    for (key, value) in [("poll", poll_field), ("option", option_field)] {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                accounts.token_program.clone(),
+                TokenMetadataUpdateField {
+                    token_program_id: accounts.token_program.clone(),
+                    metadata: accounts.mint.clone(),
+                    update_authority: accounts.treasury.clone(),
+                },
+                &[treasury_seeds],
+            ),
+            Field::Key(key.to_string()),
+            value,
+        )?;
+    }
+
+    // ── Voter's ATA, mint exactly one token, revoke mint authority ──
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        associated_token::Create {
+            payer: accounts.voter.clone(),
+            associated_token: accounts.token_account.clone(),
+            authority: accounts.voter.clone(),
+            mint: accounts.mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))?;
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_2022::MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.token_account.clone(),
+                authority: accounts.treasury.clone(),
+            },
+            &[treasury_seeds],
+        ),
+        1,
+    )?;
+    token_2022::set_authority(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            token_2022::SetAuthority {
+                current_authority: accounts.treasury.clone(),
+                account_or_mint: accounts.mint.clone(),
+            },
+            &[treasury_seeds],
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    Ok(())
+}
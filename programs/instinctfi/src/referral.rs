@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, PollAccount, ReferralAccount};
+use crate::errors::InstinctFiError;
+use crate::vault;
+
+/// Credits a referrer for `volume` lamports they brought into `poll`, on
+/// which the platform collected `fee` lamports.
+///
+/// The reward is `referral_share_bps` of that fee, capped by what is left of
+/// the poll's platform fee (so all referrals together never get more than
+/// the fee collected at creation), and moves from the treasury PDA onto the
+/// ReferralAccount. The pool and creator reward are never touched. Only poll
+/// creation pays a platform fee, so only `create_poll` takes a referrer.
+///
+/// SOL polls only: on SPL polls the referrer is ignored and earns nothing.
+pub fn pay_referral<'info>(
+    poll: &mut Account<'info, PollAccount>,
+    referrer: &mut Account<'info, ReferralAccount>,
+    config: &Option<Account<'info, PlatformConfig>>,
+    treasury: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    volume: u64,
+    fee: u64,
+) -> Result<u64> {
+    if poll.is_token_collateral() {
+        return Ok(0);
+    }
+    let config = config.as_ref().ok_or(InstinctFiError::MissingPlatformConfig)?;
+
+    let reward = config.referral_share(fee).min(poll.platform_fee);
+
+    if reward > 0 {
+        vault::withdraw(
+            &poll.key(),
+            poll.treasury_bump,
+            treasury,
+            referrer.to_account_info(),
+            system_program,
+            None,
+            reward,
+        )?;
+        poll.platform_fee = poll.platform_fee
+            .checked_sub(reward)
+            .ok_or(InstinctFiError::Overflow)?;
+    }
+
+    referrer.referred_volume = referrer.referred_volume
+        .checked_add(volume)
+        .ok_or(InstinctFiError::Overflow)?;
+    referrer.referred_count = referrer.referred_count
+        .checked_add(1)
+        .ok_or(InstinctFiError::Overflow)?;
+    referrer.total_earned = referrer.total_earned
+        .checked_add(reward)
+        .ok_or(InstinctFiError::Overflow)?;
+    Ok(reward)
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::errors::InstinctFiError;
+
+/// Domain prefix of every signed vote intent, so a signature over an intent
+/// can never be replayed as some other message.
+pub const INTENT_DOMAIN: &[u8] = b"instinctfi:relay_cast_vote:v1";
+
+/// A vote the voter signed off-chain for a relayer to submit.
+///
+/// The signed message is `INTENT_DOMAIN || borsh(intent)`; the voter signs
+/// it with their wallet key and the relayer places a matching ed25519
+/// precompile instruction immediately before `relay_cast_vote`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RelayIntent {
+    pub poll: Pubkey,
+    pub option_index: u8,
+    pub num_coins: u64,
+    /// Slippage bound, as in `cast_vote`
+    pub max_total_cost: u64,
+    /// Must equal the voter escrow's `next_relay_nonce`
+    pub nonce: u64,
+    /// Unix timestamp after which the intent is void
+    pub expires_at: i64,
+}
+
+impl RelayIntent {
+    /// Exact bytes the voter signs.
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = INTENT_DOMAIN.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
+
+// Layout of the ed25519 precompile instruction data: a 2-byte header
+// (signature count + padding) followed by one 14-byte offsets record.
+const HEADER_LEN: usize = 2;
+const OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+/// Offset "instruction index" meaning "this instruction's own data".
+const THIS_INSTRUCTION: u16 = u16::MAX;
+
+/// Checks that the instruction right before the current one is an ed25519
+/// precompile verifying exactly one signature by `signer` over `message`,
+/// with all data inline. The runtime has already verified the signature
+/// itself; this only pins down *what* was verified.
+pub fn require_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    require!(current > 0, InstinctFiError::InvalidRelaySignature);
+    let ix = load_instruction_at_checked((current - 1) as usize, instructions_sysvar)?;
+
+    require!(
+        ix.program_id == ed25519_program::ID && ix.accounts.is_empty(),
+        InstinctFiError::InvalidRelaySignature
+    );
+    let data = &ix.data;
+    require!(
+        data.len() >= HEADER_LEN + OFFSETS_LEN && data[0] == 1,
+        InstinctFiError::InvalidRelaySignature
+    );
+
+    let field = |i: usize| {
+        let at = HEADER_LEN + i * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let signature_offset = field(0) as usize;
+    let signature_ix = field(1);
+    let pubkey_offset = field(2) as usize;
+    let pubkey_ix = field(3);
+    let message_offset = field(4) as usize;
+    let message_len = field(5) as usize;
+    let message_ix = field(6);
+
+    require!(
+        signature_ix == THIS_INSTRUCTION && pubkey_ix == THIS_INSTRUCTION && message_ix == THIS_INSTRUCTION,
+        InstinctFiError::InvalidRelaySignature
+    );
+    require!(
+        data.len() >= signature_offset + SIGNATURE_LEN,
+        InstinctFiError::InvalidRelaySignature
+    );
+
+    let signed_pubkey = data.get(pubkey_offset..pubkey_offset + PUBKEY_LEN);
+    let signed_message = data.get(message_offset..message_offset + message_len);
+    require!(
+        signed_pubkey == Some(signer.as_ref()) && signed_message == Some(message),
+        InstinctFiError::InvalidRelaySignature
+    );
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::InstinctFiError;
 
 /// CRIT-04 FIX: Platform admin pubkey for receiving sweep_dust fees.
 /// Wallet: 62PFLSvnG4Zp8jYS9AFymETvV5e8xBA2JBW2UhjqyNmS
@@ -75,7 +76,7 @@ pub struct PollAccount {
     pub platform_fee: u64,
     /// Creator reward in lamports (1%, sent to creator on settlement)
     pub creator_reward: u64,
-    /// 0 = Active, 1 = Settled
+    /// 0 = Active, 1 = Settled, 2 = Voided
     pub status: u8,
     /// Winning option index (255 = unset)
     pub winning_option: u8,
@@ -87,11 +88,82 @@ pub struct PollAccount {
     pub total_voters: u32,
     /// Created-at timestamp
     pub created_at: i64,
+    /// Lamports backing outstanding outcome-token complete sets.
+    /// Held in the treasury but never part of `total_pool`.
+    pub set_collateral: u64,
+    /// Bonding curve per option (same index). Flat = fixed `unit_price`.
+    #[max_len(6)]
+    pub price_curves: Vec<PriceCurve>,
+    /// SPL collateral mint; `Pubkey::default()` = native SOL.
+    /// SPL collateral is held in the treasury PDA's associated token account.
+    pub collateral_mint: Pubkey,
+    /// Max lamports a single voter may stake on this poll (0 = no cap)
+    pub max_stake_per_voter: u64,
+    /// Max coins a single voter may hold on any one option (0 = no cap)
+    pub max_coins_per_option: u64,
+    /// Minimum `total_pool` at end_time for the poll to settle (0 = none)
+    pub min_total_pool: u64,
+    /// Minimum unique voters at end_time for the poll to settle (0 = none)
+    pub min_voters: u32,
+    /// Lamports of the pool seed the creator converted into seeded coins
+    /// (held in the creator's VoteAccount — see `seed_odds`)
+    pub creator_seeded: u64,
+    /// Lamports added to `total_pool` by third-party sponsors (`sponsor_poll`)
+    pub total_sponsored: u64,
+    /// Merkle root of eligible voters (all zeroes = open to everyone).
+    /// See `allowlist` for the leaf format.
+    pub allowlist_root: [u8; 32],
+    /// SPL mint voters must hold to take part (`Pubkey::default()` = no gate)
+    pub gate_mint: Pubkey,
+    /// Minimum gate-token balance, in base units
+    pub gate_min_amount: u64,
+    /// 0 = Market (paid option-coins), 1 = Civic (free, one vote per identity),
+    /// 2 = Ranked civic (free ranked ballots, instant-runoff tally),
+    /// 3 = Approval civic (free, one vote per approved option)
+    pub poll_kind: u8,
+    /// Civic polls only: voters must hold a valid issuer `Attestation`, and
+    /// each attested identity votes once (see `attestation`)
+    pub require_attestation: bool,
+    /// Unix timestamp when voting opens (creation time unless scheduled
+    /// with `set_start_time`)
+    pub start_time: i64,
+    /// Soft close: a vote landing within this many seconds of `end_time`
+    /// can extend the poll (0 = disabled — see `set_soft_close`)
+    pub soft_close_window: u32,
+    /// Soft close: stake that must land inside the window, in bps of the
+    /// pool when the window opened
+    pub soft_close_threshold_bps: u16,
+    /// Soft close: seconds added to `end_time` per extension
+    pub soft_close_extension: u32,
+    /// Soft close: maximum number of extensions
+    pub soft_close_max_extensions: u8,
+    /// Extensions applied so far
+    pub soft_close_extensions: u8,
+    /// Voter records refunded so far on a VOIDED poll (the creator's seed
+    /// position not included) — `sweep_dust` waits until this reaches
+    /// `total_voters`
+    pub voided_refunds: u32,
+    /// Soft close: stake accumulated inside the current close window
+    /// (reset by each extension)
+    pub soft_close_window_stake: u64,
+    /// Soft close: `total_pool` before the first vote of the current
+    /// close window
+    pub soft_close_window_base: u64,
 }
 
 impl PollAccount {
     pub const STATUS_ACTIVE: u8 = 0;
     pub const STATUS_SETTLED: u8 = 1;
+    /// Missed its liquidity thresholds — voters and creator are refunded.
+    pub const STATUS_VOIDED: u8 = 2;
+
+    pub const KIND_MARKET: u8 = 0;
+    /// Free one-person-one-vote opinion poll: no treasury, no rewards.
+    pub const KIND_CIVIC: u8 = 1;
+    /// Civic poll with ranked ballots, decided by instant runoff (`tally_ranked`).
+    pub const KIND_RANKED: u8 = 2;
+    /// Civic poll where each identity approves any subset of options.
+    pub const KIND_APPROVAL: u8 = 3;
 
     pub fn is_active(&self) -> bool {
         self.status == Self::STATUS_ACTIVE
@@ -100,6 +172,111 @@ impl PollAccount {
     pub fn is_ended(&self, clock: &Clock) -> bool {
         clock.unix_timestamp >= self.end_time
     }
+
+    pub fn has_soft_close(&self) -> bool {
+        self.soft_close_window > 0
+    }
+
+    pub fn has_started(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp >= self.start_time
+    }
+
+    /// No one but the creator holds coins: every coin in `vote_counts` is
+    /// still in the creator's `seed_odds` position. Coins resting in asks
+    /// or sold through the book count as held by others.
+    pub fn has_no_voter_coins(&self, creator_position: Option<&VoteAccount>) -> bool {
+        let seeded: u64 = creator_position
+            .map_or(0, |p| p.votes_per_option.iter().sum());
+        self.vote_counts.iter().sum::<u64>() == seeded
+    }
+
+    /// Guard shared by the pre-vote configuration instructions: the poll
+    /// must be active, not ended, and have zero votes.
+    pub fn require_editable(&self, clock: &Clock) -> Result<()> {
+        require!(self.is_active(), InstinctFiError::PollNotActive);
+        require!(!self.is_ended(clock), InstinctFiError::PollAlreadyEnded);
+        require!(self.vote_counts.iter().all(|&c| c == 0), InstinctFiError::PollHasVotes);
+        Ok(())
+    }
+
+    pub fn is_token_collateral(&self) -> bool {
+        self.collateral_mint != Pubkey::default()
+    }
+
+    /// Any non-monetary poll (single-choice, ranked or approval).
+    pub fn is_civic(&self) -> bool {
+        self.poll_kind != Self::KIND_MARKET
+    }
+
+    pub fn is_ranked(&self) -> bool {
+        self.poll_kind == Self::KIND_RANKED
+    }
+
+    pub fn is_token_gated(&self) -> bool {
+        self.gate_mint != Pubkey::default()
+    }
+
+    /// Whether voters are vetted or capped (stake caps, allowlist, token
+    /// gate). Order-book fills bypass those checks, so such polls can't
+    /// trade on the book.
+    pub fn restricts_voters(&self) -> bool {
+        self.max_stake_per_voter > 0
+            || self.max_coins_per_option > 0
+            || self.allowlist_root != [0u8; 32]
+            || self.is_token_gated()
+    }
+
+    /// Platform fee charged at creation: 1% of the investment, at least 1.
+    /// (`platform_fee` starts here and only shrinks by referral payouts.)
+    pub fn initial_platform_fee(&self) -> u64 {
+        std::cmp::max(self.creator_investment / 100, 1)
+    }
+
+    /// The creator's share of `total_pool`: investment minus fees.
+    pub fn pool_seed(&self) -> u64 {
+        self.creator_investment
+            .saturating_sub(self.initial_platform_fee())
+            .saturating_sub(self.creator_reward)
+    }
+
+    /// Whether the poll reached its `min_total_pool` and `min_voters`.
+    pub fn meets_liquidity_thresholds(&self) -> bool {
+        self.total_pool >= self.min_total_pool && self.total_voters >= self.min_voters
+    }
+
+    /// Whether two or more options share the highest, non-zero vote count —
+    /// `settle_poll` rejects such a poll and voters use `refund_tied_poll`.
+    pub fn is_tied(&self) -> bool {
+        let max_votes = self.vote_counts.iter().copied().max().unwrap_or(0);
+        max_votes > 0 && self.vote_counts.iter().filter(|&&c| c == max_votes).count() > 1
+    }
+
+    /// VOIDED polls: whether every voter counted in `total_voters` has been
+    /// refunded.
+    pub fn voided_refunds_complete(&self) -> bool {
+        self.voided_refunds >= self.total_voters
+    }
+}
+
+/// Per-option pricing rule — see `pricing::vote_cost`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PriceCurve {
+    /// 0 = Flat, 1 = Linear, 2 = Exponential, 3 = Quadratic (per voter)
+    pub kind: u8,
+    /// Linear: lamports added per coin sold. Exponential: growth in bps per coin sold.
+    /// Quadratic: unused (0).
+    pub param: u64,
+}
+
+impl PriceCurve {
+    pub const KIND_FLAT: u8 = 0;
+    pub const KIND_LINEAR: u8 = 1;
+    pub const KIND_EXPONENTIAL: u8 = 2;
+    /// n coins held by one voter on the option cost n² × unit_price in total.
+    pub const KIND_QUADRATIC: u8 = 3;
+
+    /// Exponential growth above this many bps/coin is rejected (100%).
+    pub const MAX_EXPONENTIAL_BPS: u64 = 10_000;
 }
 
 // ─── Vote Account ───────────────────────────────────────────────────────────
@@ -121,4 +298,452 @@ pub struct VoteAccount {
     pub claimed: bool,
     /// PDA bump
     pub bump: u8,
+    /// Ranked-choice polls: option indices, most preferred first
+    #[max_len(6)]
+    pub ranking: Vec<u8>,
+    /// Ranked-choice polls: last `tally_ranked` round this ballot was counted in
+    pub tallied_round: u8,
+}
+
+// ─── Order Book ─────────────────────────────────────────────────────────────
+// PDA seeds: ["order_book", poll_account.key]
+// Resting limit orders on option positions. Bid lamports and ask proceeds
+// sit in the escrow PDA ["order_escrow", poll_account.key]; ask positions
+// (coins + their share of `total_staked`) are held in the order itself.
+#[account]
+#[derive(InitSpace)]
+pub struct OrderBook {
+    /// The poll this book trades
+    pub poll: Pubkey,
+    /// Next order id — doubles as the time-priority sequence
+    pub next_order_id: u64,
+    /// Resting orders, and filled ones not yet settled by the crank or
+    /// collected with `cancel_order`
+    #[max_len(32)]
+    pub orders: Vec<Order>,
+    /// Escrow PDA bump
+    pub escrow_bump: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl OrderBook {
+    pub const MAX_ORDERS: usize = 32;
+    /// Upper bound on fills per `match_orders` call (keeps CU usage bounded)
+    pub const MAX_FILLS_PER_CRANK: u8 = 16;
+    /// Minimum order notional (quantity × price) — keeps the 32 slots from
+    /// being filled with dust orders
+    pub const MIN_ORDER_LAMPORTS: u64 = 10_000_000;
+
+    /// Fails if the book behind `info` — the poll's ["order_book", poll] PDA,
+    /// which may not exist — holds an order of `owner` (of anyone if `None`).
+    pub fn require_no_orders(info: &AccountInfo, owner: Option<&Pubkey>) -> Result<()> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(());
+        }
+        let book = Self::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            !book.orders.iter().any(|o| owner.is_none_or(|k| o.owner == *k)),
+            InstinctFiError::OpenOrders
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Order {
+    /// Unique, monotonically increasing id
+    pub order_id: u64,
+    /// Order owner
+    pub owner: Pubkey,
+    /// 0 = Bid (buy coins), 1 = Ask (sell coins)
+    pub side: u8,
+    /// Option being traded
+    pub option_index: u8,
+    /// Limit price in lamports per coin
+    pub price: u64,
+    /// Unfilled coins
+    pub remaining: u64,
+    /// Coins filled so far
+    pub filled: u64,
+    /// Bid: unspent escrowed lamports. Ask: proceeds received.
+    pub escrow_lamports: u64,
+    /// Bid: stake basis received. Ask: stake basis still escrowed.
+    pub escrow_stake: u64,
+}
+
+impl Order {
+    pub const SIDE_BID: u8 = 0;
+    pub const SIDE_ASK: u8 = 1;
+}
+
+// ─── Platform Config ────────────────────────────────────────────────────────
+// PDA seeds: ["platform_config"]
+// Platform-wide settings, written only by PLATFORM_ADMIN.
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    /// Share of the platform fee paid to referrers, in bps (10_000 = 100%)
+    pub referral_share_bps: u16,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    pub const MAX_BPS: u16 = 10_000;
+
+    /// Referrer's cut of a platform fee of `fee` lamports.
+    pub fn referral_share(&self, fee: u64) -> u64 {
+        ((fee as u128) * (self.referral_share_bps as u128) / (Self::MAX_BPS as u128)) as u64
+    }
+}
+
+// ─── Referral Account ───────────────────────────────────────────────────────
+// PDA seeds: ["referral", referrer.key]
+// Accrued referral rewards are held as lamports on this account (above its
+// rent-exempt minimum) until the referrer withdraws them.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralAccount {
+    /// Wallet credited for referrals (and the only one who can withdraw)
+    pub referrer: Pubkey,
+    /// Lifetime lamports referred (creator investments)
+    pub referred_volume: u64,
+    /// Lifetime referred poll creations
+    pub referred_count: u64,
+    /// Lifetime lamports earned
+    pub total_earned: u64,
+    /// Lifetime lamports withdrawn
+    pub total_withdrawn: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Sponsor Account ────────────────────────────────────────────────────────
+// PDA seeds: ["sponsor", poll_account.key, sponsor.key, poll.created_at.to_le_bytes()]
+// A third party's contribution to a poll's pool. Keyed by the poll's
+// `created_at` so a poll re-created at the same PDA cannot match it.
+#[account]
+#[derive(InitSpace)]
+pub struct SponsorAccount {
+    /// The sponsored poll
+    pub poll: Pubkey,
+    /// Sponsor wallet (receives refunds)
+    pub sponsor: Pubkey,
+    /// Total lamports (or collateral base units) contributed
+    pub amount: u64,
+    /// Sponsor's public note, e.g. organisation name (max 64 chars)
+    #[max_len(64)]
+    pub memo: String,
+    /// Poll collateral mint at sponsorship time (`Pubkey::default()` = SOL)
+    pub collateral_mint: Pubkey,
+    /// Poll treasury PDA bump
+    pub treasury_bump: u8,
+    /// Poll `created_at` (also a seed) — tells a re-created poll at the same PDA apart
+    pub poll_created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Session Account ────────────────────────────────────────────────────────
+// PDA seeds: ["session", authority.key, session_key]
+// Delegates voting and claiming to an ephemeral key, so apps can act without
+// a wallet popup per transaction. Votes are paid from the authority's
+// SessionEscrow and recorded under the authority; winnings go to the
+// authority's wallet.
+#[account]
+#[derive(InitSpace)]
+pub struct SessionAccount {
+    /// Wallet that granted the session
+    pub authority: Pubkey,
+    /// Ephemeral key allowed to sign on the authority's behalf
+    pub session_key: Pubkey,
+    /// Unix timestamp after which the session is no longer usable
+    pub expires_at: i64,
+    /// Max lamports the session may spend on votes
+    pub spend_cap: u64,
+    /// Lamports spent on votes so far
+    pub spent: u64,
+    /// Only this poll may be used (`Pubkey::default()` = any poll)
+    pub scope_poll: Pubkey,
+    /// Only polls in this category may be used (empty = any category)
+    #[max_len(32)]
+    pub scope_category: String,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SessionAccount {
+    /// Checks the session is unexpired and `poll` is within its scope.
+    pub fn require_usable(&self, poll: &PollAccount, poll_key: &Pubkey, now: i64) -> Result<()> {
+        require!(now < self.expires_at, InstinctFiError::SessionExpired);
+        require!(
+            self.scope_poll == Pubkey::default() || self.scope_poll == *poll_key,
+            InstinctFiError::SessionOutOfScope
+        );
+        require!(
+            self.scope_category.is_empty() || self.scope_category == poll.category,
+            InstinctFiError::SessionOutOfScope
+        );
+        Ok(())
+    }
+
+    /// Counts `cost` lamports against the spend cap.
+    pub fn charge(&mut self, cost: u64) -> Result<()> {
+        self.spent = self.spent
+            .checked_add(cost)
+            .ok_or(InstinctFiError::Overflow)?;
+        require!(self.spent <= self.spend_cap, InstinctFiError::SessionSpendCapExceeded);
+        Ok(())
+    }
+}
+
+// ─── Session Escrow ─────────────────────────────────────────────────────────
+// PDA seeds: ["session_escrow", authority.key]
+// Lamports above the rent-exempt minimum fund the authority's session and
+// relayed votes. Only the authority can withdraw them.
+#[account]
+#[derive(InitSpace)]
+pub struct SessionEscrow {
+    /// Wallet that owns the escrowed lamports
+    pub authority: Pubkey,
+    /// Nonce the next relayed vote intent must carry (replay protection)
+    pub next_relay_nonce: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Allowlist Pass ─────────────────────────────────────────────────────────
+// PDA seeds: ["allowlist", poll_account.key, voter.key]
+// Proof-of-membership in a poll's allowlist, checked on every vote. Records
+// the root it was proven against, so changing the root invalidates it.
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistPass {
+    /// The allowlisted poll
+    pub poll: Pubkey,
+    /// Eligible voter
+    pub voter: Pubkey,
+    /// Root the membership proof was verified against
+    pub root: [u8; 32],
+    /// Max total coins for this voter on the poll (0 = no cap)
+    pub max_coins: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Ranked Tally ───────────────────────────────────────────────────────────
+// PDA seeds: ["ranked_tally", poll_account.key]
+// Progress and results of the instant-runoff count of a ranked-choice poll.
+// `tally_ranked` counts ballots into `counts` for the current `round`; once
+// every ballot is in, the round is recorded and an option is eliminated or
+// the winner is written to the poll.
+#[account]
+#[derive(InitSpace)]
+pub struct RankedTally {
+    /// The ranked-choice poll
+    pub poll: Pubkey,
+    /// Current round (1-based)
+    pub round: u8,
+    /// Bitmask of eliminated options (bit i = option i)
+    pub eliminated: u8,
+    /// Current-round votes per option (same index as the poll's options)
+    #[max_len(6)]
+    pub counts: Vec<u64>,
+    /// Current-round ballots with no remaining preference
+    pub exhausted: u64,
+    /// Ballots counted so far in the current round
+    pub ballots_counted: u32,
+    /// Completed rounds: votes per option in each round
+    #[max_len(6, 6)]
+    pub round_counts: Vec<Vec<u64>>,
+    /// Option eliminated after each completed round (255 = none: final round)
+    #[max_len(6)]
+    pub round_eliminated: Vec<u8>,
+    /// Whether the count has finished (winner written to the poll)
+    pub complete: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RankedTally {
+    /// First preference on `ranking` that has not been eliminated.
+    pub fn top_choice(&self, ranking: &[u8]) -> Option<u8> {
+        ranking
+            .iter()
+            .copied()
+            .find(|&option| self.eliminated & (1 << option) == 0)
+    }
+}
+
+// ─── Attestation Issuer ─────────────────────────────────────────────────────
+// PDA seeds: ["issuer", issuer.key]
+// An identity provider whitelisted by PLATFORM_ADMIN. Removing the account
+// invalidates every attestation it issued.
+#[account]
+#[derive(InitSpace)]
+pub struct AttestationIssuer {
+    /// Key that signs `issue_attestation`
+    pub issuer: Pubkey,
+    /// When the admin added the issuer
+    pub added_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Attestation ────────────────────────────────────────────────────────────
+// PDA seeds: ["attestation", wallet.key]
+// An issuer's statement that `wallet` belongs to a verified person. Only a
+// hash of the identity is stored on-chain.
+//
+// Keyed by wallet, not identity, on purpose: a vote only carries the
+// signer, so the attestation must be derivable from the wallet alone, and a
+// person may attest several wallets (one attestation each). Uniqueness per
+// person is enforced where it matters, per poll, by `IdentityVote`, which is
+// keyed by `identity_hash`. One attestation per wallet also means a wallet
+// never speaks for two identities.
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    /// Attested wallet
+    pub wallet: Pubkey,
+    /// Issuer that signed the attestation
+    pub issuer: Pubkey,
+    /// Hash of the off-chain identity (scheme chosen by the issuer)
+    pub identity_hash: [u8; 32],
+    /// Issuer-defined region code (e.g. district / constituency)
+    pub region: u16,
+    /// Unix timestamp after which the attestation is no longer valid
+    pub expires_at: i64,
+    /// When the attestation was (last) issued
+    pub issued_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Identity Vote ──────────────────────────────────────────────────────────
+// PDA seeds: ["identity_vote", poll_account.key (or survey.key), identity_hash]
+// Created on an attestation-gated civic vote. It exists once per identity
+// per poll, so the same person cannot vote again from another wallet.
+#[account]
+#[derive(InitSpace)]
+pub struct IdentityVote {
+    /// The civic poll or survey
+    pub poll: Pubkey,
+    /// Attested identity that voted
+    pub identity_hash: [u8; 32],
+    /// Wallet the vote was cast from
+    pub voter: Pubkey,
+    /// Region of the attestation used
+    pub region: u16,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Region Tally ───────────────────────────────────────────────────────────
+// PDA seeds: ["region_tally", poll_account.key, region.to_le_bytes()]
+// Votes from one attestation region on an attestation-gated civic poll.
+// Informational only — results always come from the poll's global
+// `vote_counts`.
+#[account]
+#[derive(InitSpace)]
+pub struct RegionTally {
+    /// The civic poll
+    pub poll: Pubkey,
+    /// Attestation region code
+    pub region: u16,
+    /// Votes per option from this region (same index as the poll's options;
+    /// first preferences on ranked polls)
+    #[max_len(6)]
+    pub vote_counts: Vec<u64>,
+    /// Identities from this region that voted
+    pub voters: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Survey ─────────────────────────────────────────────────────────────────
+// PDA seeds: ["survey", creator.key, survey_id.to_le_bytes()]
+// A free civic survey: up to MAX_QUESTIONS SurveyQuestion sub-accounts
+// sharing one end time and one SurveyResponse per voter. Answers cost
+// nothing, so there is no treasury.
+#[account]
+#[derive(InitSpace)]
+pub struct Survey {
+    /// Unique survey id (scoped per creator)
+    pub survey_id: u64,
+    /// Creator's public key
+    pub creator: Pubkey,
+    /// Survey title (max 64 chars)
+    #[max_len(64)]
+    pub title: String,
+    /// Survey description (max 256 chars)
+    #[max_len(256)]
+    pub description: String,
+    /// Unix timestamp when answering closes
+    pub end_time: i64,
+    /// Questions added so far (indices 0..num_questions)
+    pub num_questions: u8,
+    /// Voters who submitted a response
+    pub total_participants: u64,
+    /// Voters must hold a valid issuer `Attestation`; one response per identity
+    pub require_attestation: bool,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Survey {
+    pub const MAX_QUESTIONS: u8 = 10;
+
+    pub fn is_ended(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp >= self.end_time
+    }
+}
+
+// ─── Survey Question ────────────────────────────────────────────────────────
+// PDA seeds: ["survey_question", survey.key, index]
+// One question of a survey and its tally.
+#[account]
+#[derive(InitSpace)]
+pub struct SurveyQuestion {
+    /// Parent survey
+    pub survey: Pubkey,
+    /// Position in the survey (0-based)
+    pub index: u8,
+    /// Question text (max 128 chars)
+    #[max_len(128)]
+    pub prompt: String,
+    /// Option labels (2–6 options, max 32 chars each)
+    #[max_len(6, 32)]
+    pub options: Vec<String>,
+    /// Answers per option (same index)
+    #[max_len(6)]
+    pub vote_counts: Vec<u64>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+// ─── Survey Response ────────────────────────────────────────────────────────
+// PDA seeds: ["survey_response", survey.key, voter.key]
+// A voter's participant record: all answers, submitted at once.
+#[account]
+#[derive(InitSpace)]
+pub struct SurveyResponse {
+    /// Parent survey
+    pub survey: Pubkey,
+    /// Respondent wallet
+    pub voter: Pubkey,
+    /// Chosen option per question (ANSWER_SKIPPED = no answer)
+    #[max_len(10)]
+    pub answers: Vec<u8>,
+    /// Submission timestamp
+    pub answered_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SurveyResponse {
+    pub const ANSWER_SKIPPED: u8 = 255;
 }
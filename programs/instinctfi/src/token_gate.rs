@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Vote-time gate shared by every instruction that buys option-coins: on a
+/// token-gated poll the voter must pass a token account they own for the
+/// poll's `gate_mint`, holding at least `gate_min_amount`.
+///
+/// A presence check only: balances are read at vote time and the gate does
+/// not lock tokens, so the same tokens can be moved on to gate another
+/// wallet. It does not bound how many coins a holder buys — use stake caps
+/// or an allowlist for that.
+pub fn require_token_gate(
+    poll: &PollAccount,
+    gate_token_account: &Option<InterfaceAccount<TokenAccount>>,
+    voter: &Pubkey,
+) -> Result<()> {
+    if !poll.is_token_gated() {
+        return Ok(());
+    }
+    let holding = gate_token_account.as_ref().ok_or(InstinctFiError::TokenGateNotMet)?;
+    require!(
+        holding.mint == poll.gate_mint
+            && holding.owner == *voter
+            && holding.amount >= poll.gate_min_amount,
+        InstinctFiError::TokenGateNotMet
+    );
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+use crate::state::PollAccount;
+use crate::errors::InstinctFiError;
+
+/// Accounts needed to move collateral of an SPL-denominated poll.
+///
+/// For SOL polls no `TokenLeg` exists and value moves as lamports in and
+/// out of the treasury PDA. For SPL polls value moves between `user` and
+/// the treasury-owned `vault` (ATA of the treasury PDA for the poll's mint).
+pub struct TokenLeg<'info> {
+    pub mint: AccountInfo<'info>,
+    pub decimals: u8,
+    pub vault: AccountInfo<'info>,
+    pub vault_amount: u64,
+    pub user: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Resolves the optional SPL accounts of an instruction against the poll's
+/// collateral: `None` for SOL polls, all four accounts required for SPL polls.
+pub fn token_leg<'info>(
+    poll: &PollAccount,
+    mint: &Option<InterfaceAccount<'info, Mint>>,
+    vault: &Option<InterfaceAccount<'info, TokenAccount>>,
+    user: &Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &Option<Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenLeg<'info>>> {
+    token_leg_for(&poll.collateral_mint, mint, vault, user, token_program)
+}
+
+/// `token_leg` for a collateral mint recorded outside the poll (e.g. on a
+/// SponsorAccount, when the poll itself may be closed).
+pub fn token_leg_for<'info>(
+    collateral_mint: &Pubkey,
+    mint: &Option<InterfaceAccount<'info, Mint>>,
+    vault: &Option<InterfaceAccount<'info, TokenAccount>>,
+    user: &Option<InterfaceAccount<'info, TokenAccount>>,
+    token_program: &Option<Interface<'info, TokenInterface>>,
+) -> Result<Option<TokenLeg<'info>>> {
+    if *collateral_mint == Pubkey::default() {
+        return Ok(None);
+    }
+    match (mint, vault, user, token_program) {
+        (Some(mint), Some(vault), Some(user), Some(token_program)) => Ok(Some(TokenLeg {
+            mint: mint.to_account_info(),
+            decimals: mint.decimals,
+            vault: vault.to_account_info(),
+            vault_amount: vault.amount,
+            user: user.to_account_info(),
+            token_program: token_program.to_account_info(),
+        })),
+        _ => err!(InstinctFiError::MissingCollateralAccounts),
+    }
+}
+
+/// Token-2022 mint extensions accepted for collateral: metadata and
+/// grouping only, which never affect transfers or balances. Anything else is
+/// rejected — including extensions that skim transfers (transfer fees),
+/// need extra accounts (transfer hooks), can move or freeze the vault's
+/// tokens (permanent delegate, default frozen state, confidential
+/// transfers), or let the mint disappear (close authority).
+pub const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 6] = [
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::TokenGroup,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+];
+
+/// Rejects Token-2022 mints with any extension outside
+/// `SUPPORTED_MINT_EXTENSIONS`.
+pub fn require_supported_mint(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let info = mint.to_account_info();
+    if *info.owner == anchor_spl::token_2022::ID {
+        let data = info.try_borrow_data()?;
+        let state = StateWithExtensions::<MintState>::unpack(&data)?;
+        let extensions = state.get_extension_types()?;
+        require!(
+            extensions.iter().all(|ext| SUPPORTED_MINT_EXTENSIONS.contains(ext)),
+            InstinctFiError::UnsupportedCollateralMint
+        );
+    }
+    Ok(())
+}
+
+/// Moves `amount` of collateral from `payer` (a signer) into the treasury.
+pub fn deposit<'info>(
+    payer: AccountInfo<'info>,
+    treasury: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    token: Option<&TokenLeg<'info>>,
+    amount: u64,
+) -> Result<()> {
+    match token {
+        None => system_program::transfer(
+            CpiContext::new(
+                system_program,
+                system_program::Transfer {
+                    from: payer,
+                    to: treasury,
+                },
+            ),
+            amount,
+        ),
+        Some(t) => token_interface::transfer_checked(
+            CpiContext::new(
+                t.token_program.clone(),
+                token_interface::TransferChecked {
+                    from: t.user.clone(),
+                    mint: t.mint.clone(),
+                    to: t.vault.clone(),
+                    authority: payer,
+                },
+            ),
+            amount,
+            t.decimals,
+        ),
+    }
+}
+
+/// Moves `amount` of collateral out of the treasury, signed by the treasury
+/// PDA. SOL goes to `recipient`; SPL tokens go to the leg's `user` account.
+pub fn withdraw<'info>(
+    poll_key: &Pubkey,
+    treasury_bump: u8,
+    treasury: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    token: Option<&TokenLeg<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"treasury", poll_key.as_ref(), &[treasury_bump]];
+    let signer_seeds = &[seeds];
+
+    match token {
+        None => system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::Transfer {
+                    from: treasury,
+                    to: recipient,
+                },
+                signer_seeds,
+            ),
+            amount,
+        ),
+        Some(t) => token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                t.token_program.clone(),
+                token_interface::TransferChecked {
+                    from: t.vault.clone(),
+                    mint: t.mint.clone(),
+                    to: t.user.clone(),
+                    authority: treasury,
+                },
+                signer_seeds,
+            ),
+            amount,
+            t.decimals,
+        ),
+    }
+}
+
+/// Full collateral balance held for the poll (lamports or vault tokens).
+pub fn balance(treasury: &AccountInfo, token: Option<&TokenLeg>) -> u64 {
+    match token {
+        None => treasury.lamports(),
+        Some(t) => t.vault_amount,
+    }
+}
+
+/// Collateral the treasury can pay out: the balance minus outcome-token
+/// collateral and, for SOL, the treasury's rent-exempt minimum.
+pub fn available(treasury: &AccountInfo, token: Option<&TokenLeg>, set_collateral: u64) -> Result<u64> {
+    let reserve = match token {
+        None => Rent::get()?.minimum_balance(0),
+        Some(_) => 0,
+    };
+    Ok(balance(treasury, token)
+        .saturating_sub(reserve)
+        .saturating_sub(set_collateral))
+}
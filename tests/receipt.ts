@@ -0,0 +1,151 @@
+import * as anchor from "@coral-xyz/anchor";
+import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getExtensionTypes,
+  getMint,
+  getTokenMetadata,
+  getAccount,
+} from "@solana/spl-token";
+import { expect } from "chai";
+
+/**
+ * Integration tests for Token-2022 participation receipts.
+ *
+ * These run against the deployed program and the Token-2022 program on a
+ * local validator: `anchor test --provider.cluster localnet`.
+ */
+describe("Vote receipts (Token-2022)", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Instinctfi as anchor.Program;
+
+  const creator = anchor.web3.Keypair.generate();
+  const voter = anchor.web3.Keypair.generate();
+  const pollId = new anchor.BN(Date.now());
+  const title = "Will the bridge open in 2027?";
+  const unitPrice = new anchor.BN(LAMPORTS_PER_SOL * 0.01);
+
+  let pollPda: PublicKey;
+  let receiptMint: PublicKey;
+  let receiptAta: PublicKey;
+
+  // Optional SPL-collateral accounts — omitted for a SOL poll.
+  const noCollateral = {
+    collateralMint: null,
+    vault: null,
+    tokenProgram: null,
+  };
+
+  before(async () => {
+    for (const kp of [creator, voter]) {
+      const sig = await provider.connection.requestAirdrop(kp.publicKey, 5 * LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(sig);
+      await program.methods
+        .initializeUser()
+        .accounts({ authority: kp.publicKey })
+        .signers([kp])
+        .rpc();
+    }
+
+    [pollPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("poll"), creator.publicKey.toBuffer(), pollId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [receiptMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("receipt"), pollPda.toBuffer(), voter.publicKey.toBuffer()],
+      program.programId
+    );
+    receiptAta = getAssociatedTokenAddressSync(
+      receiptMint,
+      voter.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const endTime = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+    await program.methods
+      .createPoll(pollId, title, "", "civic", "", ["Yes", "No"], unitPrice, endTime, unitPrice)
+      .accountsPartial({
+        creator: creator.publicKey,
+        ...noCollateral,
+        creatorTokenAccount: null,
+        associatedTokenProgram: null,
+      })
+      .signers([creator])
+      .rpc();
+  });
+
+  it("Mints a non-transferable receipt with poll metadata on the first vote", async () => {
+    await program.methods
+      .castVote(pollId, 0, new anchor.BN(2), unitPrice.muln(2))
+      .accountsPartial({
+        voter: voter.publicKey,
+        pollAccount: pollPda,
+        ...noCollateral,
+        voterTokenAccount: null,
+        receiptMint,
+        receiptTokenAccount: receiptAta,
+        receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([voter])
+      .rpc();
+
+    const mint = await getMint(provider.connection, receiptMint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(mint.supply).to.equal(BigInt(1));
+    expect(mint.decimals).to.equal(0);
+    expect(mint.mintAuthority).to.be.null;
+    expect(getExtensionTypes(mint.tlvData)).to.include(ExtensionType.NonTransferable);
+
+    const metadata = await getTokenMetadata(provider.connection, receiptMint);
+    expect(metadata?.name).to.equal(title);
+    expect(metadata?.additionalMetadata).to.deep.include(["poll", pollPda.toBase58()]);
+    expect(metadata?.additionalMetadata).to.deep.include(["option", "Yes"]);
+
+    const ata = await getAccount(provider.connection, receiptAta, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(ata.amount).to.equal(BigInt(1));
+  });
+
+  it("Does not mint a second receipt on later votes", async () => {
+    await program.methods
+      .castVote(pollId, 1, new anchor.BN(1), unitPrice)
+      .accountsPartial({
+        voter: voter.publicKey,
+        pollAccount: pollPda,
+        ...noCollateral,
+        voterTokenAccount: null,
+        receiptMint,
+        receiptTokenAccount: receiptAta,
+        receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([voter])
+      .rpc();
+
+    const mint = await getMint(provider.connection, receiptMint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    expect(mint.supply).to.equal(BigInt(1));
+  });
+
+  it("Refuses to burn the receipt while the vote record is still open", async () => {
+    try {
+      await program.methods
+        .burnReceipt(pollId)
+        .accountsPartial({
+          voter: voter.publicKey,
+          pollAccount: pollPda,
+          receiptMint,
+          receiptTokenAccount: receiptAta,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([voter])
+        .rpc();
+      expect.fail("burn_receipt should fail before settlement");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code).to.equal("VoteRecordOpen");
+    }
+  });
+});
@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import { PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
 import { expect } from "chai";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 /**
  * E2E tests for the InstinctFi prediction‑market programs.
@@ -328,4 +329,477 @@ describe("InstinctFi E2E", () => {
 
     expect(pda1.toBase58()).to.not.equal(pda2.toBase58());
   });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 5. Outcome tokens (complete sets)
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives one outcome mint PDA per option", () => {
+    // seeds: ["outcome_mint", poll_pda, option_index]
+    const mints = [0, 1, 2].map((i) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("outcome_mint"), pollPda.toBuffer(), Buffer.from([i])],
+        INSTINCTFI_PROGRAM_ID
+      )[0].toBase58()
+    );
+    expect(new Set(mints).size).to.equal(3);
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 6. Order book
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Matches by price-time priority at the maker's price", () => {
+    type Order = { id: number; side: "bid" | "ask"; price: number; remaining: number };
+    const orders: Order[] = [
+      { id: 0, side: "ask", price: 12, remaining: 5 },
+      { id: 1, side: "ask", price: 10, remaining: 3 },
+      { id: 2, side: "ask", price: 10, remaining: 4 },
+      { id: 3, side: "bid", price: 11, remaining: 5 },
+    ];
+    const best = (side: "bid" | "ask") =>
+      orders
+        .filter((o) => o.side === side && o.remaining > 0)
+        .sort((x, y) =>
+          (side === "bid" ? y.price - x.price : x.price - y.price) || x.id - y.id
+        )[0];
+
+    // Best ask is the earliest of the two 10-lamport asks
+    const ask = best("ask");
+    const bid = best("bid");
+    expect(ask.id).to.equal(1);
+    expect(bid.price >= ask.price).to.be.true;
+
+    // Resting ask (earlier id) is the maker → trade executes at 10, not 11
+    const price = bid.id < ask.id ? bid.price : ask.price;
+    expect(price).to.equal(10);
+    expect(Math.min(bid.remaining, ask.remaining)).to.equal(3);
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 7. SPL-denominated polls
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives the collateral vault as the treasury PDA's ATA", () => {
+    const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+    const usdcMint = anchor.web3.Keypair.generate().publicKey;
+
+    const [treasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), pollPda.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    // Treasury is off-curve, so the vault can only be signed for by the program.
+    expect(PublicKey.isOnCurve(treasuryPda.toBytes())).to.be.false;
+
+    const [vault] = PublicKey.findProgramAddressSync(
+      [treasuryPda.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), usdcMint.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    const [otherVault] = PublicKey.findProgramAddressSync(
+      [treasuryPda.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), anchor.web3.Keypair.generate().publicKey.toBuffer()],
+      ASSOCIATED_TOKEN_PROGRAM_ID
+    );
+    expect(vault.toBase58()).to.not.equal(otherVault.toBase58());
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 8. Referrals
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Pays referrers a share of the platform fee actually collected", () => {
+    const referralShareBps = 2_000; // 20% of the platform fee
+    const investment = 1 * LAMPORTS_PER_SOL;
+    const creationFee = Math.max(Math.floor(investment / 100), 1);
+    let platformFeeReserve = creationFee;
+
+    const pay = (feeCollected: number) => {
+      const reward = Math.min(Math.floor((feeCollected * referralShareBps) / 10_000), platformFeeReserve);
+      platformFeeReserve -= reward;
+      return reward;
+    };
+
+    // Referred creation: 20% of the 0.01 SOL creation fee
+    expect(pay(creationFee)).to.equal(2_000_000);
+    expect(platformFeeReserve).to.equal(8_000_000);
+  });
+
+  it("Derives one ReferralAccount PDA per referrer", () => {
+    const [a] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referral"), voter1.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    const [b] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referral"), voter2.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    expect(a.toBase58()).to.not.equal(b.toBase58());
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 9. Stake caps (anti-whale)
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Validates per-voter stake and per-option coin caps (off-chain)", () => {
+    const maxStakePerVoter = 0.5 * LAMPORTS_PER_SOL;
+    const maxCoinsPerOption = 30;
+    const unitPriceNum = unitPrice.toNumber();
+
+    const allowed = (staked: number, coinsOnOption: number, buy: number) =>
+      (maxStakePerVoter === 0 || staked + buy * unitPriceNum <= maxStakePerVoter) &&
+      (maxCoinsPerOption === 0 || coinsOnOption + buy <= maxCoinsPerOption);
+
+    expect(allowed(0, 0, 30)).to.be.true;
+    expect(allowed(0, 25, 10)).to.be.false; // option cap
+    expect(allowed(0.45 * LAMPORTS_PER_SOL, 0, 10)).to.be.false; // stake cap
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 10. Minimum liquidity (void thin polls)
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Voids polls below either liquidity threshold", () => {
+    const minTotalPool = 2 * LAMPORTS_PER_SOL;
+    const minVoters = 3;
+    const investment = creatorInvestment.toNumber();
+    const poolSeed = investment - 2 * Math.max(Math.floor(investment / 100), 1);
+
+    const meetsThresholds = (totalPool: number, voters: number) =>
+      totalPool >= minTotalPool && voters >= minVoters;
+
+    // Two small voters → settlement voids the poll instead
+    const totalPool = poolSeed + 0.05 * LAMPORTS_PER_SOL;
+    expect(meetsThresholds(totalPool, 2)).to.be.false;
+    expect(meetsThresholds(minTotalPool, minVoters)).to.be.true;
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 11. Creator seeding
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Seeds odds from the pool seed and pays the creator position like a voter", () => {
+    const investment = 1 * LAMPORTS_PER_SOL;
+    const unitPriceNum = unitPrice.toNumber(); // 0.01 SOL
+    const fee = Math.max(Math.floor(investment / 100), 1);
+    const poolSeed = investment - 2 * fee; // 0.98 SOL
+
+    // Creator seeds a 60/40 prior: 60 "Yes", 40 "No" coins = 1.0 SOL > pool seed
+    expect((60 + 40) * unitPriceNum).to.be.greaterThan(poolSeed);
+    // 30/20 fits: 0.5 SOL of the 0.98 SOL seed
+    const seeded = [30, 20];
+    const seedCost = (seeded[0] + seeded[1]) * unitPriceNum;
+    expect(seedCost).to.be.at.most(poolSeed);
+
+    // A voter then buys 50 "Yes" coins; the pool grows only by the voter's cost
+    const voterCoins = 50;
+    const totalPool = poolSeed + voterCoins * unitPriceNum;
+    const yesVotes = seeded[0] + voterCoins;
+
+    // "Yes" wins: rewards split by coins, creator position included
+    const creatorReward = Math.floor((seeded[0] * totalPool) / yesVotes);
+    const voterReward = Math.floor((voterCoins * totalPool) / yesVotes);
+    expect(creatorReward + voterReward).to.be.at.most(totalPool);
+    expect(voterReward).to.be.greaterThan(voterCoins * unitPriceNum);
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 12. Sponsorship
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives one SponsorAccount per sponsor and poll instance", () => {
+    const sponsorRecord = (sponsor: PublicKey, createdAt: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("sponsor"),
+          pollPda.toBuffer(),
+          sponsor.toBuffer(),
+          new anchor.BN(createdAt).toArrayLike(Buffer, "le", 8),
+        ],
+        INSTINCTFI_PROGRAM_ID
+      )[0].toBase58();
+
+    const createdAt = 1_700_000_000;
+    expect(sponsorRecord(voter1.publicKey, createdAt)).to.not.equal(sponsorRecord(voter2.publicKey, createdAt));
+    // A poll re-created at the same PDA never matches an old sponsorship
+    expect(sponsorRecord(voter1.publicKey, createdAt)).to.not.equal(
+      sponsorRecord(voter1.publicKey, createdAt + 60)
+    );
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 13. Session keys
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives one SessionAccount per wallet and session key, one escrow per wallet", () => {
+    const sessionKey = anchor.web3.Keypair.generate().publicKey;
+    const [session] = PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), voter1.publicKey.toBuffer(), sessionKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    const [otherSession] = PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), voter2.publicKey.toBuffer(), sessionKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    const [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("session_escrow"), voter1.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    expect(session.toBase58()).to.not.equal(otherSession.toBase58());
+    expect(escrow.toBase58()).to.not.equal(session.toBase58());
+  });
+
+  it("Session spend cap bounds cumulative vote cost", () => {
+    const spendCap = 0.05 * LAMPORTS_PER_SOL;
+    const unitPrice = 0.01 * LAMPORTS_PER_SOL;
+    let spent = 0;
+    const votes = [2, 2, 2].map((coins) => {
+      const cost = coins * unitPrice;
+      if (spent + cost > spendCap) return false;
+      spent += cost;
+      return true;
+    });
+    expect(votes).to.deep.equal([true, true, false]);
+    expect(spent).to.equal(0.04 * LAMPORTS_PER_SOL);
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 14. Relayed votes
+  // ────────────────────────────────────────────────────────────────────────
+
+  const encodeIntent = (poll: PublicKey, optionIndex: number, numCoins: number, maxTotalCost: number, nonce: number, expiresAt: number) => {
+    const body = Buffer.alloc(32 + 1 + 8 * 4);
+    poll.toBuffer().copy(body, 0);
+    body.writeUInt8(optionIndex, 32);
+    body.writeBigUInt64LE(BigInt(numCoins), 33);
+    body.writeBigUInt64LE(BigInt(maxTotalCost), 41);
+    body.writeBigUInt64LE(BigInt(nonce), 49);
+    body.writeBigInt64LE(BigInt(expiresAt), 57);
+    return Buffer.concat([Buffer.from("instinctfi:relay_cast_vote:v1"), body]);
+  };
+
+  it("Builds an ed25519 instruction carrying the voter's key and intent inline", () => {
+    const expiresAt = Math.floor(Date.now() / 1000) + 600;
+    const message = encodeIntent(pollPda, 0, 3, 0.03 * LAMPORTS_PER_SOL, 0, expiresAt);
+    const ix = anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: voter1.secretKey,
+      message,
+    });
+
+    const data = ix.data;
+    expect(data[0]).to.equal(1); // one signature
+    const pubkeyOffset = data.readUInt16LE(6);
+    const messageOffset = data.readUInt16LE(10);
+    const messageLen = data.readUInt16LE(12);
+    // All offsets point into the instruction's own data (u16::MAX)
+    expect(data.readUInt16LE(4)).to.equal(0xffff);
+    expect(data.readUInt16LE(8)).to.equal(0xffff);
+    expect(data.readUInt16LE(14)).to.equal(0xffff);
+    expect(data.subarray(pubkeyOffset, pubkeyOffset + 32).equals(voter1.publicKey.toBuffer())).to.be.true;
+    expect(data.subarray(messageOffset, messageOffset + messageLen).equals(message)).to.be.true;
+  });
+
+  it("Distinct nonces produce distinct signed intents", () => {
+    const expiresAt = Math.floor(Date.now() / 1000) + 600;
+    const first = encodeIntent(pollPda, 1, 1, 0.01 * LAMPORTS_PER_SOL, 0, expiresAt);
+    const second = encodeIntent(pollPda, 1, 1, 0.01 * LAMPORTS_PER_SOL, 1, expiresAt);
+    expect(first.equals(second)).to.be.false;
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 15. Allowlists
+  // ────────────────────────────────────────────────────────────────────────
+
+  // Mirrors `allowlist::leaf` / `allowlist::node` in the program
+  const allowlistLeaf = (voter: PublicKey, maxCoins: number) => {
+    const cap = Buffer.alloc(8);
+    cap.writeBigUInt64LE(BigInt(maxCoins));
+    return Buffer.from(keccak_256(Buffer.concat([Buffer.from([0]), voter.toBuffer(), cap])));
+  };
+  const allowlistNode = (a: Buffer, b: Buffer) => {
+    const [lo, hi] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+    return Buffer.from(keccak_256(Buffer.concat([Buffer.from([1]), lo, hi])));
+  };
+
+  it("Verifies sorted-pair merkle proofs for allowlisted voters", () => {
+    const leaves = [
+      allowlistLeaf(voter1.publicKey, 0),
+      allowlistLeaf(voter2.publicKey, 10),
+      allowlistLeaf(creator.publicKey, 5),
+    ];
+    // Odd leaf is carried up unchanged
+    const root = allowlistNode(allowlistNode(leaves[0], leaves[1]), leaves[2]);
+
+    const proofVoter2 = [leaves[0], leaves[2]];
+    const computed = proofVoter2.reduce((acc, sibling) => allowlistNode(acc, sibling), leaves[1]);
+    expect(computed.equals(root)).to.be.true;
+
+    // A different cap is a different leaf — the proof no longer holds
+    const forged = proofVoter2.reduce(
+      (acc, sibling) => allowlistNode(acc, sibling),
+      allowlistLeaf(voter2.publicKey, 1000)
+    );
+    expect(forged.equals(root)).to.be.false;
+  });
+
+  it("Derives one AllowlistPass per poll and voter", () => {
+    const [a] = PublicKey.findProgramAddressSync(
+      [Buffer.from("allowlist"), pollPda.toBuffer(), voter1.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    const [b] = PublicKey.findProgramAddressSync(
+      [Buffer.from("allowlist"), pollPda.toBuffer(), voter2.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    expect(a.toBase58()).to.not.equal(b.toBase58());
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 16. Civic polls
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Civic tallies count one vote per identity and report ties as 255", () => {
+    // Mirrors `finalize_civic_poll`
+    const outcome = (tallies: number[]) => {
+      const max = Math.max(...tallies);
+      const leaders = tallies.filter((t) => t === max).length;
+      return max === 0 || leaders > 1 ? 255 : tallies.indexOf(max);
+    };
+
+    const ballots = [0, 1, 0, 2, 0]; // one entry per identity
+    const tallies = [0, 0, 0];
+    ballots.forEach((option) => tallies[option]++);
+
+    expect(tallies).to.deep.equal([3, 1, 1]);
+    expect(outcome(tallies)).to.equal(0);
+    expect(outcome([2, 2, 0])).to.equal(255);
+    expect(outcome([0, 0])).to.equal(255);
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 17. Approval voting
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Approval bitmasks add one vote per approved option", () => {
+    const numOptions = 4;
+    const validBits = (1 << numOptions) - 1;
+    const isValid = (approvals: number) => approvals !== 0 && (approvals & ~validBits) === 0;
+
+    expect(isValid(0b0000)).to.be.false;
+    expect(isValid(0b10000)).to.be.false; // option 4 does not exist
+    expect(isValid(0b0101)).to.be.true;
+
+    const tallies = new Array(numOptions).fill(0);
+    [0b0101, 0b0110, 0b0100].forEach((approvals) => {
+      for (let i = 0; i < numOptions; i++) if (approvals & (1 << i)) tallies[i]++;
+    });
+    expect(tallies).to.deep.equal([1, 1, 3, 0]);
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 18. Identity attestations
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives one IdentityVote per identity per poll, whatever the wallet", () => {
+    const identityHash = Buffer.from(keccak_256(Buffer.from("issuer-salt:citizen-42")));
+    const [attestation1] = PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), voter1.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    const [attestation2] = PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), voter2.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    // Two wallets attested to the same person hold separate attestations…
+    expect(attestation1.toBase58()).to.not.equal(attestation2.toBase58());
+
+    // …but claim the same IdentityVote on a poll, so only one can vote
+    const identityVote = (poll: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("identity_vote"), poll.toBuffer(), identityHash],
+        INSTINCTFI_PROGRAM_ID
+      )[0];
+    const [otherPoll] = PublicKey.findProgramAddressSync(
+      [Buffer.from("poll"), creator.publicKey.toBuffer(), new anchor.BN(2).toArrayLike(Buffer, "le", 8)],
+      INSTINCTFI_PROGRAM_ID
+    );
+    expect(identityVote(pollPda).toBase58()).to.not.equal(identityVote(otherPoll).toBase58());
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 19. Regional tallies
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives one RegionTally per poll and region", () => {
+    const regionTally = (region: number) => {
+      const code = Buffer.alloc(2);
+      code.writeUInt16LE(region);
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("region_tally"), pollPda.toBuffer(), code],
+        INSTINCTFI_PROGRAM_ID
+      )[0];
+    };
+    expect(regionTally(1).toBase58()).to.not.equal(regionTally(2).toBase58());
+    // The region code is little-endian: 256 is not region 1
+    expect(regionTally(256).toBase58()).to.not.equal(regionTally(1).toBase58());
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 20. Surveys
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Derives survey questions by index and one response per voter", () => {
+    const [surveyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("survey"), creator.publicKey.toBuffer(), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
+      INSTINCTFI_PROGRAM_ID
+    );
+    const questions = [0, 1, 2].map(
+      (index) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("survey_question"), surveyPda.toBuffer(), Buffer.from([index])],
+          INSTINCTFI_PROGRAM_ID
+        )[0].toBase58()
+    );
+    expect(new Set(questions).size).to.equal(3);
+
+    // Surveys and polls with the same id never share an address
+    expect(surveyPda.toBase58()).to.not.equal(pollPda.toBase58());
+
+    const [response] = PublicKey.findProgramAddressSync(
+      [Buffer.from("survey_response"), surveyPda.toBuffer(), voter1.publicKey.toBuffer()],
+      INSTINCTFI_PROGRAM_ID
+    );
+    expect(response.toBase58()).to.not.equal(surveyPda.toBase58());
+  });
+
+  it("Validates survey answers: one per question, 255 skips", () => {
+    const SKIPPED = 255;
+    const optionCounts = [2, 4, 3];
+    // Mirrors `answer_survey`
+    const isValid = (answers: number[]) =>
+      answers.length === optionCounts.length &&
+      answers.some((a) => a !== SKIPPED) &&
+      answers.every((a, i) => a === SKIPPED || a < optionCounts[i]);
+
+    expect(isValid([1, 3, 0])).to.be.true;
+    expect(isValid([0, SKIPPED, 2])).to.be.true;
+    expect(isValid([SKIPPED, SKIPPED, SKIPPED])).to.be.false;
+    expect(isValid([2, 0, 0])).to.be.false; // question 0 has 2 options
+    expect(isValid([0, 0])).to.be.false; // must cover every question
+  });
+
+  // ────────────────────────────────────────────────────────────────────────
+  // 21. Scheduled start
+  // ────────────────────────────────────────────────────────────────────────
+
+  it("Accepts votes only between start_time and end_time", () => {
+    const now = Math.floor(Date.now() / 1000);
+    const startTime = now + 7 * 24 * 60 * 60; // announced a week ahead
+    const endTime = startTime + 2 * 60 * 60;
+    // Mirrors `require_open_for_votes`: has_started && !is_ended
+    const isOpen = (t: number) => t >= startTime && t < endTime;
+
+    expect(isOpen(now)).to.be.false; // announced, not yet open
+    expect(isOpen(startTime)).to.be.true;
+    expect(isOpen(endTime)).to.be.false;
+  });
 });